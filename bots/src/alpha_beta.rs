@@ -0,0 +1,377 @@
+use engine::{Action, Game};
+
+use crate::{
+    transposition::{Bound, Entry, TranspositionTable},
+    Agent, Evaluator,
+};
+
+pub const WIN_SCORE: i32 = 1_000_000;
+const MATE_BOUND: i32 = WIN_SCORE - 10_000;
+const TABLE_SIZE: usize = 1 << 16;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchResult {
+    pub action: Option<Action>,
+    pub score: i32,
+    pub principal_variation: Vec<Action>,
+    pub nodes: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct AlphaBeta<E> {
+    depth: usize,
+    evaluator: E,
+    table: TranspositionTable,
+    nodes: u64,
+}
+
+impl<E: Evaluator> AlphaBeta<E> {
+    pub fn new(depth: usize, evaluator: E) -> Self {
+        Self {
+            depth: depth.max(1),
+            evaluator,
+            table: TranspositionTable::new(TABLE_SIZE),
+            nodes: 0,
+        }
+    }
+
+    pub fn with_table(mut self, table: TranspositionTable) -> Self {
+        self.table = table;
+        self
+    }
+
+    pub fn search(&mut self, game: &Game) -> SearchResult {
+        let mut game = game.clone();
+        let mut principal_variation = Vec::new();
+        let mut score = self.evaluator.evaluate(&game);
+        self.nodes = 0;
+        for depth in 1..=self.depth {
+            principal_variation.clear();
+            score = self.negamax(
+                &mut game,
+                depth,
+                0,
+                -WIN_SCORE,
+                WIN_SCORE,
+                &mut principal_variation,
+            );
+        }
+        SearchResult {
+            action: principal_variation.first().copied(),
+            score,
+            principal_variation,
+            nodes: self.nodes,
+        }
+    }
+
+    fn negamax(
+        &mut self,
+        game: &mut Game,
+        depth: usize,
+        ply: usize,
+        mut alpha: i32,
+        beta: i32,
+        principal_variation: &mut Vec<Action>,
+    ) -> i32 {
+        self.nodes += 1;
+        if game.is_over() {
+            return terminal_score(game, ply);
+        }
+        let mut actions = game.legal_actions();
+        if depth == 0 || actions.is_empty() {
+            return self.evaluator.evaluate(game);
+        }
+        let hash = game.hash();
+        let is_draw_near = is_draw_near(game, depth);
+        if let Some(entry) = self.table.probe(hash) {
+            let score = from_table(entry.score, ply);
+            let is_cutoff = match entry.bound {
+                Bound::Exact => true,
+                Bound::Lower => score >= beta,
+                Bound::Upper => score <= alpha,
+            };
+            if ply > 0 && entry.depth >= depth && is_cutoff && !is_draw_near {
+                if entry.bound == Bound::Exact {
+                    principal_variation.extend(entry.action);
+                }
+                return score;
+            }
+            if let Some(index) = actions
+                .iter()
+                .position(|action| Some(*action) == entry.action)
+            {
+                actions[..=index].rotate_right(1);
+            }
+        }
+        let original_alpha = alpha;
+        let player = game.current_player();
+        let mut best = -WIN_SCORE;
+        for action in actions.iter() {
+            let undo = game.apply(action).unwrap();
+            let mut line = Vec::new();
+            let score = if game.current_player() == player {
+                self.negamax(game, depth - 1, ply + 1, alpha, beta, &mut line)
+            } else {
+                -self.negamax(game, depth - 1, ply + 1, -beta, -alpha, &mut line)
+            };
+            game.revert(undo);
+            if score > best || principal_variation.is_empty() {
+                best = score;
+                principal_variation.clear();
+                principal_variation.push(*action);
+                principal_variation.append(&mut line);
+            }
+            alpha = alpha.max(score);
+            if alpha >= beta {
+                break;
+            }
+        }
+        let bound = if best <= original_alpha {
+            Bound::Upper
+        } else if best >= beta {
+            Bound::Lower
+        } else {
+            Bound::Exact
+        };
+        if !is_draw_near {
+            self.table.store(Entry {
+                hash,
+                depth,
+                score: to_table(best, ply),
+                bound,
+                action: principal_variation.first().copied(),
+            });
+        }
+        best
+    }
+}
+
+pub(crate) fn terminal_score(game: &Game, ply: usize) -> i32 {
+    match game.winner() {
+        Some(winner) if winner == game.current_player() => WIN_SCORE - ply as i32,
+        Some(_) => ply as i32 - WIN_SCORE,
+        None => 0,
+    }
+}
+
+// The table is keyed by the position alone, but repetition and ply limit draws
+// also depend on the path that led there. Near either limit a score from the
+// table may be wrong for the current path, so such nodes neither use nor fill
+// it. Draws deeper in a stored subtree can still leak through an entry.
+fn is_draw_near(game: &Game, depth: usize) -> bool {
+    let rule_set = game.rule_set();
+    let repetition = matches!(rule_set.repetition, Some(limit) if game.occurrence() + 1 >= limit);
+    let ply_limit = matches!(rule_set.ply_limit, Some(limit) if game.ply() + depth >= limit);
+    repetition || ply_limit
+}
+
+// Wins are stored relative to the node rather than the root so that an entry
+// stays valid wherever the position is reached again.
+fn to_table(score: i32, ply: usize) -> i32 {
+    if score > MATE_BOUND {
+        score + ply as i32
+    } else if score < -MATE_BOUND {
+        score - ply as i32
+    } else {
+        score
+    }
+}
+
+fn from_table(score: i32, ply: usize) -> i32 {
+    if score > MATE_BOUND {
+        score - ply as i32
+    } else if score < -MATE_BOUND {
+        score + ply as i32
+    } else {
+        score
+    }
+}
+
+// Scores in the table depend on how often positions had occurred when they
+// were stored, which changes from move to move, so each move starts afresh.
+impl<E: Evaluator> Agent for AlphaBeta<E> {
+    fn select(&mut self, game: &Game) -> Option<Action> {
+        self.table.clear();
+        self.search(game).action
+    }
+}
+
+#[cfg(test)]
+mod alpha_beta_spec {
+    use super::{terminal_score, AlphaBeta, WIN_SCORE};
+    use crate::{
+        fixture,
+        transposition::{Bound, Entry},
+        Agent, RandomBot, TranspositionTable,
+    };
+    use engine::{rule::RuleSet, Action, Game};
+
+    fn material(game: &Game) -> i32 {
+        let player = game.current_player();
+        game.board
+            .iterate()
+            .filter_map(|position| game.board.cell(&position).unwrap().owner())
+            .map(|owner| if owner == player { 1 } else { -1 })
+            .sum()
+    }
+
+    fn minimax(game: &Game, depth: usize, ply: usize) -> i32 {
+        if game.is_over() {
+            return terminal_score(game, ply);
+        }
+        if depth == 0 {
+            return material(game);
+        }
+        game.legal_actions()
+            .iter()
+            .map(|action| {
+                let next = game.accept(action).unwrap();
+                let score = minimax(&next, depth - 1, ply + 1);
+                if next.current_player() == game.current_player() {
+                    score
+                } else {
+                    -score
+                }
+            })
+            .max()
+            .unwrap()
+    }
+
+    #[test]
+    fn finds_immediate_win() {
+        let game = fixture::win_in_one();
+        let result = AlphaBeta::new(3, material).search(&game);
+        assert_eq!(result.score, WIN_SCORE - 1);
+        let next = game.accept(&result.action.unwrap()).unwrap();
+        assert_eq!(next.winner(), Some(game.current_player()));
+        assert_eq!(result.principal_variation.len(), 1);
+    }
+
+    #[test]
+    fn searches_at_least_one_ply() {
+        let game = Game::default();
+        let result = AlphaBeta::new(0, material).search(&game);
+        assert!(game.legal_actions().contains(&result.action.unwrap()));
+        assert_eq!(result.principal_variation.len(), 1);
+    }
+
+    #[test]
+    fn sees_forced_loss() {
+        let game = fixture::facing_win_in_one();
+        let result = AlphaBeta::new(2, material).search(&game);
+        assert_eq!(result.score, 2 - WIN_SCORE);
+    }
+
+    #[test]
+    fn agrees_with_minimax() {
+        let mut random = RandomBot::new(22);
+        let mut game = Game::default();
+        for _ in 0..12 {
+            for depth in 1..=2 {
+                let result = AlphaBeta::new(depth, material).search(&game);
+                assert_eq!(result.score, minimax(&game, depth, 0), "{}", game);
+                let mut line = game.clone();
+                for action in result.principal_variation.iter() {
+                    line.apply(action).unwrap();
+                }
+                assert!(result.principal_variation.len() <= depth);
+            }
+            game.apply(&random.select(&game).unwrap()).unwrap();
+        }
+    }
+
+    #[test]
+    fn principal_variation_is_playable() {
+        let game = fixture::play(&Game::default(), &["c1-S", "a6-N"]);
+        let mut bot = AlphaBeta::new(3, material);
+        let result = bot.search(&game);
+        assert_eq!(result.principal_variation.len(), 3);
+        assert_eq!(bot.select(&game), result.action);
+        let end = result
+            .principal_variation
+            .iter()
+            .fold(game, |game, action| game.accept(action).unwrap());
+        assert_eq!(-material(&end), result.score);
+    }
+
+    #[test]
+    fn table_is_reused() {
+        let game = Game::default();
+        let mut bot = AlphaBeta::new(3, material);
+        let first = bot.search(&game);
+        let second = bot.search(&game);
+        assert_eq!(second.score, first.score);
+        assert!(second.nodes < first.nodes);
+        let fresh = AlphaBeta::new(3, material)
+            .with_table(TranspositionTable::new(1))
+            .search(&game);
+        assert_eq!(fresh.score, first.score);
+    }
+
+    #[test]
+    fn table_size_does_not_change_scores() {
+        let mut random = RandomBot::new(25);
+        let mut game = Game::default();
+        for _ in 0..4 {
+            let score = minimax(&game, 3, 0);
+            for capacity in [1, 7, 1 << 12].iter() {
+                let result = AlphaBeta::new(3, material)
+                    .with_table(TranspositionTable::new(*capacity))
+                    .search(&game);
+                assert_eq!(result.score, score, "{}", game);
+            }
+            game.apply(&random.select(&game).unwrap()).unwrap();
+        }
+    }
+
+    #[test]
+    fn ignores_table_near_draw_rules() {
+        let game = Game::new(RuleSet {
+            ply_limit: Some(2),
+            ..RuleSet::default()
+        })
+        .unwrap();
+        let mut table = TranspositionTable::new(1 << 12);
+        for action in game.legal_actions().iter() {
+            let next = game.accept(action).unwrap();
+            table.store(Entry {
+                hash: next.hash(),
+                depth: 10,
+                score: 500,
+                bound: Bound::Exact,
+                action: next.legal_actions().first().copied(),
+            });
+        }
+        let result = AlphaBeta::new(2, |_: &Game| 0)
+            .with_table(table)
+            .search(&game);
+        assert_eq!(result.score, 0);
+        assert_eq!(result.principal_variation.len(), 2);
+    }
+
+    #[test]
+    fn select_forgets_entries_hiding_a_draw() {
+        let shuttle = ["a1-S", "a6-N", "a2-N", "a5-S"];
+        let game = fixture::play(&fixture::play(&Game::default(), &shuttle), &shuttle[..3]);
+        let draw = shuttle[3].parse::<Action>().unwrap();
+        assert!(game.accept(&draw).unwrap().is_draw());
+        let mut table = TranspositionTable::new(1 << 12);
+        for action in game
+            .legal_actions()
+            .iter()
+            .filter(|action| **action != draw)
+        {
+            let next = game.accept(action).unwrap();
+            table.store(Entry {
+                hash: next.hash(),
+                depth: 10,
+                score: -500,
+                bound: Bound::Exact,
+                action: next.legal_actions().first().copied(),
+            });
+        }
+        let mut bot = AlphaBeta::new(2, |_: &Game| -10).with_table(table);
+        assert_ne!(bot.clone().search(&game).action, Some(draw));
+        assert_eq!(bot.select(&game), Some(draw));
+    }
+}
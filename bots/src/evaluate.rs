@@ -0,0 +1,162 @@
+use engine::{player::Player, Game};
+
+pub trait Evaluator {
+    fn evaluate(&self, game: &Game) -> i32;
+}
+
+impl<F: Fn(&Game) -> i32> Evaluator for F {
+    fn evaluate(&self, game: &Game) -> i32 {
+        self(game)
+    }
+}
+
+fn balance(game: &Game, score: impl Fn(&Game, &Player) -> i32) -> i32 {
+    score(game, &game.current_player()) - score(game, &game.opponent())
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GoalDistance;
+
+impl Evaluator for GoalDistance {
+    fn evaluate(&self, game: &Game) -> i32 {
+        balance(game, |game, player| {
+            let goal_side = game.goal_side_of(player) as i32;
+            let rows = game.rule_set().rows as i32;
+            game.board
+                .territory(player)
+                .map(|(position, _)| rows - 1 - (goal_side - position.y() as i32).abs())
+                .sum()
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Isolation;
+
+impl Evaluator for Isolation {
+    fn evaluate(&self, game: &Game) -> i32 {
+        balance(game, |game, player| {
+            game.board
+                .territory(player)
+                .filter(|(position, _)| game.board.is_isolated(position))
+                .count() as i32
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Buried;
+
+impl Evaluator for Buried {
+    fn evaluate(&self, game: &Game) -> i32 {
+        balance(game, |game, player| {
+            game.board
+                .territory(player)
+                .map(|(_, cell)| cell.players().filter(|below| below != player).count() as i32)
+                .sum()
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Mobility;
+
+impl Evaluator for Mobility {
+    fn evaluate(&self, game: &Game) -> i32 {
+        balance(game, |game, player| {
+            game.board
+                .territory(player)
+                .map(|(position, _)| game.board.moveable_directions(&position).len() as i32)
+                .sum()
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct Weighted {
+    terms: Vec<(i32, Box<dyn Evaluator>)>,
+}
+
+impl Weighted {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn standard() -> Self {
+        Self::new()
+            .term(10, GoalDistance)
+            .term(4, Isolation)
+            .term(6, Buried)
+            .term(1, Mobility)
+    }
+
+    pub fn term(mut self, weight: i32, evaluator: impl Evaluator + 'static) -> Self {
+        self.terms.push((weight, Box::new(evaluator)));
+        self
+    }
+}
+
+impl Evaluator for Weighted {
+    fn evaluate(&self, game: &Game) -> i32 {
+        self.terms
+            .iter()
+            .map(|(weight, evaluator)| weight * evaluator.evaluate(game))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod evaluate_spec {
+    use super::{Buried, Evaluator, GoalDistance, Isolation, Mobility, Weighted};
+    use crate::{fixture, play, AlphaBeta, RandomBot};
+    use engine::Game;
+
+    #[test]
+    fn initial_position_is_balanced() {
+        let game = Game::default();
+        assert_eq!(GoalDistance.evaluate(&game), 0);
+        assert_eq!(Isolation.evaluate(&game), 0);
+        assert_eq!(Buried.evaluate(&game), 0);
+        assert_eq!(Mobility.evaluate(&game), 0);
+        assert_eq!(Weighted::standard().evaluate(&game), 0);
+    }
+
+    #[test]
+    fn stock_heuristics() {
+        let a_to_move = engine::game!(a;
+            [. . . .]
+            [. ba . .]
+            [. . . .]
+            [. . b b]
+        );
+        let b_to_move = engine::game!(b;
+            [. . . .]
+            [. ba . .]
+            [. . . .]
+            [. . b b]
+        );
+        let cases: Vec<(Box<dyn Evaluator>, i32)> = vec![
+            (Box::new(GoalDistance), 1),
+            (Box::new(Isolation), 1 - 2),
+            (Box::new(Buried), 1),
+            (Box::new(Mobility), 8 - (4 + 2)),
+        ];
+        for (evaluator, expected) in cases.iter() {
+            assert_eq!(evaluator.evaluate(&a_to_move), *expected);
+            assert_eq!(evaluator.evaluate(&b_to_move), -*expected);
+        }
+        let closure = |game: &Game| game.ply() as i32 + 1;
+        assert_eq!(closure.evaluate(&a_to_move), 1);
+        let weighted = Weighted::new().term(2, GoalDistance).term(-1, Buried);
+        assert_eq!(weighted.evaluate(&a_to_move), 2 - 1);
+    }
+
+    #[test]
+    fn alpha_beta_takes_any_evaluator() {
+        let game = fixture::small();
+        let mut searcher = AlphaBeta::new(2, Weighted::standard());
+        let mut random = RandomBot::new(24);
+        let played = play(&game, &mut [&mut searcher, &mut random]);
+        assert_eq!(played.winner(), Some(game.current_player()));
+    }
+}
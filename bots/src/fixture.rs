@@ -0,0 +1,29 @@
+use engine::{rule::RuleSet, Action, Game};
+
+// Player a wins on the spot by moving c5 onto the goal row away from b's
+// pieces; with b to move, b cannot stop it.
+const WIN_IN_ONE: &str = "5/5/5/5/2a1b/a3b";
+
+pub(crate) fn win_in_one() -> Game {
+    Game::from_fen(&format!("{} a", WIN_IN_ONE), RuleSet::default()).unwrap()
+}
+
+pub(crate) fn facing_win_in_one() -> Game {
+    Game::from_fen(&format!("{} b", WIN_IN_ONE), RuleSet::default()).unwrap()
+}
+
+// Short games for tests that play to the end.
+pub(crate) fn small() -> Game {
+    Game::new(RuleSet {
+        columns: 4,
+        rows: 4,
+        ..RuleSet::default()
+    })
+    .unwrap()
+}
+
+pub(crate) fn play(game: &Game, actions: &[&str]) -> Game {
+    actions.iter().fold(game.clone(), |game, action| {
+        game.accept(&action.parse::<Action>().unwrap()).unwrap()
+    })
+}
@@ -0,0 +1,98 @@
+use engine::{player::Player, Action, Game};
+
+use crate::{rng::Rng, Agent};
+
+#[derive(Debug, Clone)]
+pub struct GreedyBot {
+    rng: Rng,
+}
+
+impl GreedyBot {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: Rng::new(seed),
+        }
+    }
+
+    fn is_lost(game: &Game, me: Player) -> bool {
+        matches!(game.winner(), Some(winner) if winner != me)
+    }
+
+    fn lets_opponent_win(game: &mut Game, me: Player) -> bool {
+        game.legal_actions().iter().any(|action| {
+            let undo = game.apply(action).unwrap();
+            let lost = Self::is_lost(game, me);
+            game.revert(undo);
+            lost
+        })
+    }
+}
+
+impl Agent for GreedyBot {
+    fn select(&mut self, game: &Game) -> Option<Action> {
+        let me = game.current_player();
+        let mut game = game.clone();
+        let actions = game.legal_actions();
+        let mut safe = Vec::new();
+        for action in actions.iter() {
+            let undo = game.apply(action).unwrap();
+            let won = game.winner() == Some(me);
+            let lost = Self::is_lost(&game, me) || Self::lets_opponent_win(&mut game, me);
+            game.revert(undo);
+            if won {
+                return Some(*action);
+            } else if !lost {
+                safe.push(*action);
+            }
+        }
+        self.rng
+            .choose(&safe)
+            .or_else(|| self.rng.choose(&actions))
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod greedy_spec {
+    use super::GreedyBot;
+    use crate::{fixture, Agent, RandomBot};
+    use engine::{
+        outcome::{Outcome, WinReason},
+        Action, Game,
+    };
+
+    #[test]
+    fn takes_immediate_win() {
+        let game = fixture::win_in_one();
+        for seed in 0..5 {
+            let action = GreedyBot::new(seed).select(&game).unwrap();
+            let next = game.accept(&action).unwrap();
+            assert_eq!(
+                next.outcome(),
+                Outcome::Win(game.current_player(), WinReason::Isolation)
+            );
+        }
+    }
+
+    #[test]
+    fn avoids_losing_reply() {
+        let mut random = RandomBot::new(0);
+        let mut game = Game::default();
+        while !game.is_over() {
+            let me = game.current_player();
+            let loses = |action: &Action| {
+                let next = game.accept(action).unwrap();
+                GreedyBot::is_lost(&next, me)
+                    || next
+                        .legal_actions()
+                        .iter()
+                        .any(|reply| GreedyBot::is_lost(&next.accept(reply).unwrap(), me))
+            };
+            let action = GreedyBot::new(game.ply() as u64).select(&game).unwrap();
+            if game.legal_actions().iter().any(|action| !loses(action)) {
+                assert!(!loses(&action), "{}\n{}", game, action);
+            }
+            game.apply(&random.select(&game).unwrap()).unwrap();
+        }
+    }
+}
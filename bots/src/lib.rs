@@ -0,0 +1,87 @@
+pub mod alpha_beta;
+pub mod evaluate;
+#[cfg(test)]
+mod fixture;
+pub mod greedy;
+pub mod mcts;
+pub mod random;
+mod rng;
+pub mod transposition;
+
+use engine::{Action, Game};
+
+pub use alpha_beta::AlphaBeta;
+pub use evaluate::Evaluator;
+pub use greedy::GreedyBot;
+pub use mcts::{Budget, Mcts};
+pub use random::RandomBot;
+pub use transposition::TranspositionTable;
+
+pub trait Agent {
+    fn select(&mut self, game: &Game) -> Option<Action>;
+}
+
+// An agent that answers with an illegal move resigns, so a faulty agent loses
+// the game instead of aborting the match.
+pub fn play(game: &Game, agents: &mut [&mut dyn Agent; 2]) -> Game {
+    let mut game = game.clone();
+    while !game.is_over() {
+        let player = game.current_player();
+        match agents[player.id].select(&game) {
+            Some(action) => {
+                if game.apply(&action).is_err() {
+                    game = game.resign(&player).unwrap();
+                }
+            }
+            None => break,
+        }
+    }
+    game
+}
+
+#[cfg(test)]
+mod play_spec {
+    use super::{play, Agent, GreedyBot, RandomBot};
+    use engine::{
+        outcome::{Outcome, WinReason},
+        Action, Game,
+    };
+
+    struct Cheater;
+
+    impl Agent for Cheater {
+        fn select(&mut self, _: &Game) -> Option<Action> {
+            "a6-N".parse::<Action>().ok()
+        }
+    }
+
+    #[test]
+    fn greedy_beats_random() {
+        let mut wins = 0;
+        for seed in 0..6 {
+            let mut greedy = GreedyBot::new(seed);
+            let mut random = RandomBot::new(seed);
+            let game = if seed % 2 == 0 {
+                play(&Game::default(), &mut [&mut greedy, &mut random])
+            } else {
+                play(&Game::default(), &mut [&mut random, &mut greedy])
+            };
+            assert!(game.is_over());
+            if game.winner().map(|player| player.id) == Some((seed % 2) as usize) {
+                wins += 1;
+            }
+        }
+        assert!(wins >= 5, "greedy won {} of 6", wins);
+    }
+
+    #[test]
+    fn illegal_move_forfeits() {
+        let game = Game::default();
+        let played = play(&game, &mut [&mut Cheater, &mut RandomBot::new(0)]);
+        assert_eq!(
+            played.outcome(),
+            Outcome::Win(game.opponent(), WinReason::Resignation)
+        );
+        assert_eq!(played.ply(), 0);
+    }
+}
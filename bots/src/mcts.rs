@@ -0,0 +1,247 @@
+use std::time::{Duration, Instant};
+
+use engine::{player::Player, Action, Game};
+
+use crate::{rng::Rng, Agent};
+
+const PLAYOUT_LIMIT: usize = 300;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Budget {
+    Iterations(usize),
+    Time(Duration),
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    action: Option<Action>,
+    player: Option<Player>,
+    visits: u32,
+    reward: f64,
+    children: Vec<Node>,
+    untried: Vec<Action>,
+}
+
+impl Node {
+    fn new(action: Option<Action>, player: Option<Player>, game: &Game) -> Self {
+        Self {
+            action,
+            player,
+            visits: 0,
+            reward: 0.0,
+            children: Vec::new(),
+            untried: game.legal_actions(),
+        }
+    }
+
+    fn iterate(&mut self, game: &mut Game, rng: &mut Rng, exploration: f64) -> Option<Player> {
+        let winner = if game.is_over() {
+            game.winner()
+        } else if !self.untried.is_empty() {
+            let action = self.untried.swap_remove(rng.below(self.untried.len()));
+            let player = game.current_player();
+            game.apply(&action).unwrap();
+            let mut child = Self::new(Some(action), Some(player), game);
+            let winner = playout(game, rng);
+            child.update(winner);
+            self.children.push(child);
+            winner
+        } else {
+            let child = self.select_child(exploration);
+            game.apply(&child.action.unwrap()).unwrap();
+            child.iterate(game, rng, exploration)
+        };
+        self.update(winner);
+        winner
+    }
+
+    fn select_child(&mut self, exploration: f64) -> &mut Self {
+        let log_visits = (self.visits as f64).ln();
+        let uct = |child: &Self| {
+            child.reward / child.visits as f64
+                + exploration * (log_visits / child.visits as f64).sqrt()
+        };
+        let mut best = 0;
+        for index in 1..self.children.len() {
+            if uct(&self.children[index]) > uct(&self.children[best]) {
+                best = index;
+            }
+        }
+        &mut self.children[best]
+    }
+
+    fn update(&mut self, winner: Option<Player>) {
+        self.visits += 1;
+        self.reward += match winner {
+            Some(winner) if Some(winner) == self.player => 1.0,
+            Some(_) => 0.0,
+            None => 0.5,
+        };
+    }
+
+    fn best_action(&self) -> Option<Action> {
+        self.children
+            .iter()
+            .max_by_key(|child| child.visits)
+            .and_then(|child| child.action)
+    }
+
+    fn find(self, current: &Game, target: &Game, depth: usize) -> Option<Self> {
+        if current.hash() == target.hash() && current == target {
+            return Some(self);
+        }
+        if depth == 0 {
+            return None;
+        }
+        self.children.into_iter().find_map(|child| {
+            let next = current.accept(&child.action.unwrap()).ok()?;
+            child.find(&next, target, depth - 1)
+        })
+    }
+}
+
+fn playout(game: &mut Game, rng: &mut Rng) -> Option<Player> {
+    for _ in 0..PLAYOUT_LIMIT {
+        if game.is_over() {
+            break;
+        }
+        let actions = game.legal_actions();
+        game.apply(&actions[rng.below(actions.len())]).unwrap();
+    }
+    game.winner()
+}
+
+#[derive(Debug, Clone)]
+pub struct Mcts {
+    budget: Budget,
+    exploration: f64,
+    rng: Rng,
+    tree: Option<(Game, Node)>,
+}
+
+impl Mcts {
+    pub fn new(budget: Budget, seed: u64) -> Self {
+        Self {
+            budget,
+            exploration: std::f64::consts::SQRT_2,
+            rng: Rng::new(seed),
+            tree: None,
+        }
+    }
+
+    pub fn with_exploration(mut self, exploration: f64) -> Self {
+        self.exploration = exploration;
+        self
+    }
+
+    fn take_subtree(&mut self, game: &Game) -> Option<Node> {
+        let (previous, root) = self.tree.take()?;
+        root.find(&previous, game, 2)
+    }
+}
+
+impl Agent for Mcts {
+    fn select(&mut self, game: &Game) -> Option<Action> {
+        if game.is_over() {
+            return None;
+        }
+        let mut root = self
+            .take_subtree(game)
+            .unwrap_or_else(|| Node::new(None, None, game));
+        let started = Instant::now();
+        let mut iterations = 0;
+        loop {
+            let exhausted = match self.budget {
+                Budget::Iterations(limit) => iterations >= limit.max(1),
+                Budget::Time(limit) => iterations > 0 && started.elapsed() >= limit,
+            };
+            if exhausted {
+                break;
+            }
+            root.iterate(&mut game.clone(), &mut self.rng, self.exploration);
+            iterations += 1;
+        }
+        let action = root.best_action();
+        self.tree = Some((game.clone(), root));
+        action
+    }
+}
+
+#[cfg(test)]
+mod mcts_spec {
+    use super::{Budget, Mcts};
+    use crate::{fixture, play, Agent, RandomBot};
+    use engine::{Action, Game};
+    use std::time::Duration;
+
+    #[test]
+    fn seeded() {
+        let game = fixture::small();
+        let pick = |seed| Mcts::new(Budget::Iterations(100), seed).select(&game);
+        assert_eq!(pick(1), pick(1));
+        assert!(game.legal_actions().contains(&pick(2).unwrap()));
+    }
+
+    #[test]
+    fn finds_immediate_win() {
+        let game = fixture::win_in_one();
+        let action = Mcts::new(Budget::Iterations(500), 0).select(&game).unwrap();
+        assert_eq!(
+            game.accept(&action).unwrap().winner(),
+            Some(game.current_player())
+        );
+    }
+
+    #[test]
+    fn reuses_tree() {
+        let mut bot = Mcts::new(Budget::Iterations(200), 0);
+        let game = fixture::small();
+        let action = bot.select(&game).unwrap();
+        let game = game.accept(&action).unwrap();
+        let reply = game.legal_actions()[0];
+        let game = game.accept(&reply).unwrap();
+        let (_, root) = bot.tree.clone().unwrap();
+        let expected = root
+            .children
+            .iter()
+            .find(|child| child.action == Some(action))
+            .and_then(|child| {
+                child
+                    .children
+                    .iter()
+                    .find(|child| child.action == Some(reply))
+            })
+            .map(|child| child.visits)
+            .unwrap_or(0);
+        assert!(expected > 0);
+        assert_eq!(bot.take_subtree(&game).unwrap().visits, expected);
+        assert!(bot.take_subtree(&game).is_none());
+        bot.select(&game).unwrap();
+        let unrelated = fixture::small().accept(&"d1-S".parse::<Action>().unwrap());
+        assert!(bot.take_subtree(&unrelated.unwrap()).is_none());
+    }
+
+    #[test]
+    fn time_budget() {
+        let mut bot = Mcts::new(Budget::Time(Duration::from_millis(20)), 0);
+        let game = Game::default();
+        assert!(game.legal_actions().contains(&bot.select(&game).unwrap()));
+        let over = game.resign(&game.current_player()).unwrap();
+        assert_eq!(bot.select(&over), None);
+    }
+
+    #[test]
+    fn runs_at_least_one_iteration() {
+        let game = Game::default();
+        let action = Mcts::new(Budget::Iterations(0), 0).select(&game);
+        assert!(game.legal_actions().contains(&action.unwrap()));
+    }
+
+    #[test]
+    fn beats_random() {
+        let mut mcts = Mcts::new(Budget::Iterations(100), 23);
+        let mut random = RandomBot::new(23);
+        let game = play(&fixture::small(), &mut [&mut mcts, &mut random]);
+        assert_eq!(game.winner(), Some(fixture::small().current_player()));
+    }
+}
@@ -0,0 +1,52 @@
+use engine::{Action, Game};
+
+use crate::{rng::Rng, Agent};
+
+#[derive(Debug, Clone)]
+pub struct RandomBot {
+    rng: Rng,
+}
+
+impl RandomBot {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: Rng::new(seed),
+        }
+    }
+}
+
+impl Agent for RandomBot {
+    fn select(&mut self, game: &Game) -> Option<Action> {
+        self.rng.choose(&game.legal_actions()).copied()
+    }
+}
+
+#[cfg(test)]
+mod random_spec {
+    use super::RandomBot;
+    use crate::Agent;
+    use engine::Game;
+
+    #[test]
+    fn seeded() {
+        let game = Game::default();
+        let picks = |seed| {
+            let mut bot = RandomBot::new(seed);
+            (0..20)
+                .map(|_| bot.select(&game).unwrap())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(picks(1), picks(1));
+        assert_ne!(picks(1), picks(2));
+        assert!(picks(3)
+            .iter()
+            .all(|action| game.legal_actions().contains(action)));
+    }
+
+    #[test]
+    fn nothing_to_select() {
+        let game = Game::default();
+        let game = game.resign(&game.current_player()).unwrap();
+        assert_eq!(RandomBot::new(0).select(&game), None);
+    }
+}
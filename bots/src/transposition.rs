@@ -0,0 +1,98 @@
+use engine::Action;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bound {
+    Exact,
+    Lower,
+    Upper,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entry {
+    pub hash: u64,
+    pub depth: usize,
+    pub score: i32,
+    pub bound: Bound,
+    pub action: Option<Action>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TranspositionTable {
+    entries: Vec<Option<Entry>>,
+}
+
+impl TranspositionTable {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: vec![None; capacity.max(1)],
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn probe(&self, hash: u64) -> Option<Entry> {
+        self.entries[self.index(hash)].filter(|entry| entry.hash == hash)
+    }
+
+    pub fn store(&mut self, entry: Entry) {
+        let index = self.index(entry.hash);
+        let slot = &mut self.entries[index];
+        match slot {
+            Some(stored) if stored.depth > entry.depth => {}
+            _ => *slot = Some(entry),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.iter_mut().for_each(|entry| *entry = None);
+    }
+
+    fn index(&self, hash: u64) -> usize {
+        (hash % self.entries.len() as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod transposition_spec {
+    use super::{Bound, Entry, TranspositionTable};
+    use engine::Action;
+
+    fn entry(hash: u64, depth: usize, score: i32) -> Entry {
+        Entry {
+            hash,
+            depth,
+            score,
+            bound: Bound::Exact,
+            action: Some("a1-S".parse::<Action>().unwrap()),
+        }
+    }
+
+    #[test]
+    fn store_and_probe() {
+        let mut table = TranspositionTable::new(8);
+        assert_eq!(table.capacity(), 8);
+        assert_eq!(table.probe(3), None);
+        table.store(entry(3, 2, 10));
+        assert_eq!(table.probe(3), Some(entry(3, 2, 10)));
+        assert_eq!(table.probe(11), None);
+        table.clear();
+        assert_eq!(table.probe(3), None);
+        assert_eq!(TranspositionTable::new(0).capacity(), 1);
+    }
+
+    #[test]
+    fn replace_by_depth() {
+        let mut table = TranspositionTable::new(8);
+        table.store(entry(3, 4, 10));
+        table.store(entry(11, 2, 20));
+        assert_eq!(table.probe(3), Some(entry(3, 4, 10)));
+        assert_eq!(table.probe(11), None);
+        table.store(entry(3, 3, 30));
+        assert_eq!(table.probe(3), Some(entry(3, 4, 10)));
+        table.store(entry(11, 4, 40));
+        assert_eq!(table.probe(3), None);
+        assert_eq!(table.probe(11), Some(entry(11, 4, 40)));
+    }
+}
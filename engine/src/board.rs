@@ -1,51 +1,136 @@
 use std::collections::{BTreeSet, HashMap};
 use std::iter::FromIterator;
+use std::ops::{Deref, DerefMut};
+use std::{fmt, str::FromStr};
 
 use crate::{
     cell::Cell,
+    diagram,
     player::Player,
-    position::{Column, Position, Row},
+    position::Position,
     result::{Error, Result},
+    rule::RuleSet,
+    zobrist,
 };
 
 pub type CellMap = HashMap<Position, Cell>;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+// Boards up to 8x8 keep their cells inline so cloning a game never touches the
+// heap; larger variants fall back to a boxed slice sized to the rule set.
+const INLINE_CELLS: usize = 64;
+
+#[derive(Clone, PartialEq, Eq)]
+enum Cells {
+    Inline([Cell; INLINE_CELLS]),
+    Boxed(Box<[Cell]>),
+}
+
+impl Cells {
+    fn new(len: usize) -> Self {
+        if len <= INLINE_CELLS {
+            Self::Inline([Cell::new_empty(); INLINE_CELLS])
+        } else {
+            Self::Boxed(vec![Cell::new_empty(); len].into_boxed_slice())
+        }
+    }
+}
+
+impl Deref for Cells {
+    type Target = [Cell];
+
+    fn deref(&self) -> &[Cell] {
+        match self {
+            Self::Inline(cells) => cells,
+            Self::Boxed(cells) => cells,
+        }
+    }
+}
+
+impl DerefMut for Cells {
+    fn deref_mut(&mut self) -> &mut [Cell] {
+        match self {
+            Self::Inline(cells) => cells,
+            Self::Boxed(cells) => cells,
+        }
+    }
+}
+
+/// With the `serde` feature a board is written as
+/// `{"cell_map": [[{"x": 0, "y": 0}, [0]], ...], "rule_set": {...}}`, one
+/// position and `Cell` per entry. Omitted positions are empty; reading rejects
+/// an invalid rule set, positions off the field or listed twice and stacks
+/// higher than the rule set's `pallet_height`.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "CellList", try_from = "CellList"))]
 pub struct Board {
-    pub cell_map: CellMap,
+    cells: Cells,
+    rule_set: RuleSet,
+    hash: u64,
 }
 
 impl Board {
-    pub(crate) fn new(player_a: &Player, player_b: &Player) -> Self {
-        let cell_map = Self::build_initial_cell_map(player_a, player_b);
-        Self { cell_map }
+    pub(crate) fn new(rule_set: RuleSet, player_a: &Player, player_b: &Player) -> Self {
+        let cell_map = Self::build_initial_cell_map(&rule_set, player_a, player_b);
+        Self::from_cell_map(rule_set, cell_map)
+    }
+
+    pub(crate) fn from_cell_map(rule_set: RuleSet, cell_map: CellMap) -> Self {
+        let mut board = Self {
+            cells: Cells::new(rule_set.columns * rule_set.rows),
+            rule_set,
+            hash: 0,
+        };
+        cell_map
+            .into_iter()
+            .for_each(|(position, cell)| board.put(&position, cell));
+        board
+    }
+
+    pub fn rule_set(&self) -> &RuleSet {
+        &self.rule_set
+    }
+
+    pub fn cell_map(&self) -> CellMap {
+        self.iterate()
+            .map(|position| (position, self.cells[self.index(&position)]))
+            .collect()
     }
 
     pub fn iterate(&self) -> impl Iterator<Item = Position> {
-        Row::iterator().flat_map(|row| {
-            Column::iterator().map(move |col| Position::new(col.clone(), row.clone()))
-        })
+        let RuleSet { columns, rows, .. } = self.rule_set;
+        (0..rows).flat_map(move |y| (0..columns).map(move |x| Position::at(x, y)))
+    }
+
+    fn index(&self, position: &Position) -> usize {
+        position.y() * self.rule_set.columns + position.x()
+    }
+
+    pub(crate) fn put(&mut self, position: &Position, cell: Cell) {
+        debug_assert!(position.is_inside(&self.rule_set));
+        let index = self.index(position);
+        self.hash ^= zobrist::cell(index, &self.cells[index]) ^ zobrist::cell(index, &cell);
+        self.cells[index] = cell;
     }
 
-    fn build_initial_cell_map(player_a: &Player, player_b: &Player) -> CellMap {
+    pub(crate) fn hash(&self) -> u64 {
+        self.hash
+    }
+
+    fn build_initial_cell_map(rule_set: &RuleSet, player_a: &Player, player_b: &Player) -> CellMap {
         let mut cell_map = CellMap::new();
-        let player_a_side_cells = Self::generate_initial_occupied_cells(player_a.clone(), Row::Top);
+        let bottom = rule_set.rows - 1;
+        let player_a_side_cells = Self::generate_initial_occupied_cells(rule_set, *player_a, 0);
         player_a_side_cells.for_each(|(position, cell)| {
             cell_map.insert(position, cell);
         });
-        let empty_rows = [
-            Row::MiddleFirst,
-            Row::MiddleSecond,
-            Row::MiddleThird,
-            Row::MiddleFourth,
-        ]
-        .iter()
-        .flat_map(|row| Self::generate_initial_empty_cells(row.to_owned()));
+        let empty_rows =
+            (1..bottom).flat_map(|row| Self::generate_initial_empty_cells(rule_set, row));
         empty_rows.for_each(|(position, cell)| {
             cell_map.insert(position, cell);
         });
         let player_b_side_cells =
-            Self::generate_initial_occupied_cells(player_b.clone(), Row::Bottom);
+            Self::generate_initial_occupied_cells(rule_set, *player_b, bottom);
         player_b_side_cells.for_each(|(position, cell)| {
             cell_map.insert(position, cell);
         });
@@ -53,46 +138,36 @@ impl Board {
     }
 
     fn generate_initial_occupied_cells(
+        rule_set: &RuleSet,
         player: Player,
-        side: Row,
+        side: usize,
     ) -> impl Iterator<Item = (Position, Cell)> {
-        [
-            Column::LeftEdge,
-            Column::MiddleFirst,
-            Column::MiddleSecond,
-            Column::MiddleThird,
-            Column::RightEdge,
-        ]
-        .iter()
-        .map(move |column| {
-            let position = Position::new(column.to_owned(), side.clone());
-            let cell = Cell::new_occupied(player.clone());
+        (0..rule_set.columns).map(move |column| {
+            let position = Position::at(column, side);
+            let cell = Cell::new_occupied(player);
             (position, cell)
         })
     }
 
-    fn generate_initial_empty_cells(row: Row) -> impl Iterator<Item = (Position, Cell)> {
-        [
-            Column::LeftEdge,
-            Column::MiddleFirst,
-            Column::MiddleSecond,
-            Column::MiddleThird,
-            Column::RightEdge,
-        ]
-        .iter()
-        .map(move |column| {
-            let position = Position::new(column.to_owned(), row.clone());
+    fn generate_initial_empty_cells(
+        rule_set: &RuleSet,
+        row: usize,
+    ) -> impl Iterator<Item = (Position, Cell)> {
+        (0..rule_set.columns).map(move |column| {
+            let position = Position::at(column, row);
             let cell = Cell::new_empty();
             (position, cell)
         })
     }
 
     pub(crate) fn is_occupied_by(&self, position: &Position, player: &Player) -> bool {
-        self.territory(player).contains_key(position)
+        self.cell(position)
+            .map(|cell| cell.owner() == Some(*player))
+            .unwrap_or(false)
     }
 
-    pub(crate) fn is_isolated(&self, position: &Position) -> bool {
-        let cell = self.cell_of(position);
+    pub fn is_isolated(&self, position: &Position) -> bool {
+        let cell = self.cell(position);
         if cell.is_err() {
             return false;
         }
@@ -102,7 +177,7 @@ impl Board {
             return true;
         }
         let owner = owner.unwrap();
-        let moving_range = MovingRange::new(position, &self.cell_map);
+        let moving_range = self.moving_range_of(position);
         if moving_range.is_err() {
             return false;
         }
@@ -118,81 +193,135 @@ impl Board {
             moving_range.down_left,
         ]
         .iter()
-        .filter(|dest| {
+        .all(|dest| {
             let dest_point = dest.reveal();
             if dest_point.is_none() {
-                return false;
+                return true;
             }
             let dest_owner = dest_point.unwrap().cell.owner();
             if dest_owner.is_none() {
-                return false;
+                return true;
             }
-            &owner != &dest_owner.unwrap()
+            owner == dest_owner.unwrap()
         })
-        .collect::<Vec<&DestinationState>>()
-        .len()
-            == 0
     }
 
-    pub(crate) fn is_reached_edge(&self, player: &Player, row: &Row) -> bool {
-        if row.is_middle() {
+    pub(crate) fn is_reached_edge(&self, player: &Player, row: usize) -> bool {
+        if row != 0 && row + 1 != self.rule_set.rows {
             return false;
         }
         self.territory(player)
-            .keys()
-            .find(|position| match row {
-                Row::Top => position.is_top(),
-                _ => position.is_bottom(),
-            })
-            .is_some()
+            .any(|(position, _)| position.y() == row)
     }
 
-    pub(crate) fn territory(&self, player: &Player) -> CellMap {
-        self.cell_map
-            .iter()
-            .fold(CellMap::new(), |mut acc, (position, cell)| {
-                if let Some(owner) = cell.owner() {
-                    if owner == player.clone() {
-                        acc.insert(position.clone(), cell.clone());
-                    }
-                }
-                acc
-            })
+    pub fn territory<'a>(
+        &'a self,
+        player: &'a Player,
+    ) -> impl Iterator<Item = (Position, Cell)> + 'a {
+        self.iterate()
+            .map(move |position| (position, self.cells[self.index(&position)]))
+            .filter(move |(_, cell)| cell.owner() == Some(*player))
     }
 
-    pub(crate) fn cell_of(&self, position: &Position) -> Result<Cell> {
-        self.cell_map
-            .get(position)
-            .ok_or(Error::InvalidPosition)
-            .map(|x| x.clone())
+    pub fn cell(&self, position: &Position) -> Result<Cell> {
+        if position.is_inside(&self.rule_set) {
+            Ok(self.cells[self.index(position)])
+        } else {
+            Err(Error::InvalidPosition)
+        }
     }
 
-    pub(crate) fn migrate(&self, from: &Position, to: &Position) -> Result<Self> {
+    pub(crate) fn migrate(&mut self, from: &Position, to: &Position) -> Result<()> {
         if from == to {
             return Err(Error::SamePositionCannotBeMigrated);
         }
-        let from_cell = self.cell_of(from)?;
-        let to_cell = self.cell_of(to)?;
+        let from_cell = self.cell(from)?;
+        let to_cell = self.cell(to)?;
         if from_cell.is_empty() {
             return Err(Error::CellIsEmpty);
-        } else if to_cell.is_fullfilled() {
+        } else if to_cell.is_fullfilled(self.rule_set.pallet_height) {
             return Err(Error::CellIsFullfilled);
         }
         let owner = from_cell.owner();
         let destination_owner = to_cell.owner();
         if owner == destination_owner {
-            return Err(Error::AlreadyOccupied(destination_owner.unwrap()));
+            return Err(Error::AlreadyOccupied(*to, destination_owner.unwrap()));
         }
         let migrated_from_cell = from_cell.unstack()?;
-        let migrated_to_cell = to_cell.stack(&owner.unwrap())?;
-        let mut cell_map = self.cell_map.clone();
-        cell_map.insert(from.clone(), migrated_from_cell.clone());
-        cell_map.insert(to.clone(), migrated_to_cell.clone());
-        Ok(Self { cell_map })
+        let migrated_to_cell = to_cell.stack(&owner.unwrap(), self.rule_set.pallet_height)?;
+        self.put(from, migrated_from_cell);
+        self.put(to, migrated_to_cell);
+        Ok(())
     }
 
     pub(crate) fn moving_range_of(&self, pivot_position: &Position) -> Result<MovingRange> {
-        MovingRange::new(&pivot_position, &self.cell_map)
+        MovingRange::new(pivot_position, self)
+    }
+
+    pub fn moveable_directions(&self, position: &Position) -> BTreeSet<Direction> {
+        self.moving_range_of(position)
+            .map(|moving_range| moving_range.moveable_directions())
+            .unwrap_or_default()
+    }
+}
+
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        diagram::write_board(self, f)
+    }
+}
+
+impl fmt::Debug for Board {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let RuleSet { columns, rows, .. } = self.rule_set;
+        f.debug_struct("Board")
+            .field("cells", &&self.cells[..columns * rows])
+            .field("rule_set", &self.rule_set)
+            .finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct CellList {
+    cell_map: Vec<(Position, Cell)>,
+    rule_set: RuleSet,
+}
+
+#[cfg(feature = "serde")]
+impl From<Board> for CellList {
+    fn from(board: Board) -> Self {
+        Self {
+            cell_map: board
+                .iterate()
+                .map(|position| (position, board.cells[board.index(&position)]))
+                .collect(),
+            rule_set: board.rule_set,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<CellList> for Board {
+    type Error = &'static str;
+
+    fn try_from(list: CellList) -> std::result::Result<Self, Self::Error> {
+        if list.rule_set.validate().is_err() {
+            return Err("invalid rule set");
+        }
+        let mut cell_map = CellMap::new();
+        for (position, cell) in list.cell_map.into_iter() {
+            if !position.is_inside(&list.rule_set) {
+                return Err("cell is out of the field");
+            }
+            if cell.height() > list.rule_set.pallet_height {
+                return Err("stack is higher than the pallet height");
+            }
+            if cell_map.insert(position, cell).is_some() {
+                return Err("cell is listed twice");
+            }
+        }
+        Ok(Self::from_cell_map(list.rule_set, cell_map))
     }
 }
 
@@ -247,7 +376,8 @@ pub(crate) struct MovingRange {
     pub(crate) down_left: DestinationState,
 }
 
-#[derive(Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Direction {
     Up,
     Down,
@@ -260,45 +390,114 @@ pub enum Direction {
 }
 
 impl Direction {
-    pub(crate) fn destination(&self, from: &Position) -> Result<Position> {
+    pub fn iterator() -> impl Iterator<Item = Self> {
+        [
+            Self::Up,
+            Self::Down,
+            Self::Right,
+            Self::Left,
+            Self::UpRight,
+            Self::DownRight,
+            Self::UpLeft,
+            Self::DownLeft,
+        ]
+        .iter()
+        .copied()
+    }
+
+    pub(crate) fn destination(&self, from: &Position, rule_set: &RuleSet) -> Result<Position> {
         match self {
             Self::Up => from.above(),
-            Self::Down => from.below(),
-            Self::Right => from.righthand(),
+            Self::Down => from.below(rule_set),
+            Self::Right => from.righthand(rule_set),
             Self::Left => from.lefthand(),
-            Self::UpRight => from.above_righthand(),
-            Self::DownRight => from.below_righthand(),
+            Self::UpRight => from.above_righthand(rule_set),
+            Self::DownRight => from.below_righthand(rule_set),
             Self::UpLeft => from.above_lefthand(),
-            Self::DownLeft => from.below_lefthand(),
+            Self::DownLeft => from.below_lefthand(rule_set),
+        }
+    }
+
+    pub(crate) fn between(from: &Position, to: &Position) -> Result<Self> {
+        let dx = to.x() as isize - from.x() as isize;
+        let dy = to.y() as isize - from.y() as isize;
+        match (dx, dy) {
+            (0, -1) => Ok(Self::Up),
+            (0, 1) => Ok(Self::Down),
+            (1, 0) => Ok(Self::Right),
+            (-1, 0) => Ok(Self::Left),
+            (1, -1) => Ok(Self::UpRight),
+            (1, 1) => Ok(Self::DownRight),
+            (-1, -1) => Ok(Self::UpLeft),
+            (-1, 1) => Ok(Self::DownLeft),
+            _ => Err(Error::NotAdjacent(*from, *to)),
+        }
+    }
+
+    fn symbol(&self) -> &'static str {
+        match self {
+            Self::Up => "N",
+            Self::Down => "S",
+            Self::Right => "E",
+            Self::Left => "W",
+            Self::UpRight => "NE",
+            Self::DownRight => "SE",
+            Self::UpLeft => "NW",
+            Self::DownLeft => "SW",
         }
     }
 }
 
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+impl FromStr for Direction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::iterator()
+            .find(|direction| direction.symbol() == s)
+            .ok_or(Error::InvalidNotation)
+    }
+}
+
 impl MovingRange {
-    fn new(pivot_position: &Position, cell_map: &CellMap) -> Result<Self> {
-        let cell = cell_map.get(pivot_position).ok_or(Error::CellNotFound)?;
+    fn new(pivot_position: &Position, board: &Board) -> Result<Self> {
+        let cell = board
+            .cell(pivot_position)
+            .map_err(|_| Error::CellNotFound)?;
+        let destination = |direction: Direction| {
+            Self::destination(
+                &cell,
+                direction.destination(pivot_position, board.rule_set()),
+                board,
+            )
+        };
         Ok(Self {
-            pivot: Point::new(pivot_position.clone(), cell.clone()),
-            up: Self::destination(cell, pivot_position.above(), cell_map),
-            down: Self::destination(cell, pivot_position.below(), cell_map),
-            left: Self::destination(cell, pivot_position.lefthand(), cell_map),
-            right: Self::destination(cell, pivot_position.righthand(), cell_map),
-            up_right: Self::destination(cell, pivot_position.above_righthand(), cell_map),
-            down_right: Self::destination(cell, pivot_position.below_righthand(), cell_map),
-            up_left: Self::destination(cell, pivot_position.above_lefthand(), cell_map),
-            down_left: Self::destination(cell, pivot_position.below_lefthand(), cell_map),
+            pivot: Point::new(*pivot_position, cell),
+            up: destination(Direction::Up),
+            down: destination(Direction::Down),
+            left: destination(Direction::Left),
+            right: destination(Direction::Right),
+            up_right: destination(Direction::UpRight),
+            down_right: destination(Direction::DownRight),
+            up_left: destination(Direction::UpLeft),
+            down_left: destination(Direction::DownLeft),
         })
     }
 
-    fn destination(pivot: &Cell, moved: Result<Position>, cell_map: &CellMap) -> DestinationState {
+    fn destination(pivot: &Cell, moved: Result<Position>, board: &Board) -> DestinationState {
         if moved.is_err() {
             return DestinationState::OutOfField;
         }
         let dest_position = moved.unwrap();
-        if let Some(dest_cell) = cell_map.get(&dest_position) {
-            if Self::is_reached_stacking_limit(dest_cell) {
+        if let Ok(dest_cell) = board.cell(&dest_position) {
+            if Self::is_reached_stacking_limit(&dest_cell, board.rule_set()) {
                 DestinationState::Fullfilled(Point::new(dest_position, dest_cell.clone()))
-            } else if pivot.is_same_owner(dest_cell) {
+            } else if pivot.is_same_owner(&dest_cell) {
                 DestinationState::AlreadyOwned(Point::new(dest_position, dest_cell.clone()))
             } else {
                 DestinationState::Moveable(Point::new(dest_position, dest_cell.clone()))
@@ -308,12 +507,18 @@ impl MovingRange {
         }
     }
 
-    fn is_reached_stacking_limit(to_cell: &Cell) -> bool {
-        to_cell.is_fullfilled()
+    fn is_reached_stacking_limit(to_cell: &Cell, rule_set: &RuleSet) -> bool {
+        to_cell.is_fullfilled(rule_set.pallet_height)
     }
 
     pub(crate) fn indicate(&self, direction: &Direction) -> Result<Point> {
-        let x = match direction {
+        self.state(direction)
+            .reveal()
+            .ok_or(Error::IllegalDestination(self.pivot.position, *direction))
+    }
+
+    pub(crate) fn state(&self, direction: &Direction) -> DestinationState {
+        match direction {
             Direction::Up => self.up,
             Direction::Down => self.down,
             Direction::Right => self.right,
@@ -322,8 +527,22 @@ impl MovingRange {
             Direction::DownRight => self.down_right,
             Direction::UpLeft => self.up_left,
             Direction::DownLeft => self.down_left,
-        };
-        x.reveal().ok_or(Error::IllegalDestination)
+        }
+    }
+
+    pub(crate) fn is_moveable(&self) -> bool {
+        [
+            self.up,
+            self.down,
+            self.right,
+            self.left,
+            self.up_right,
+            self.down_right,
+            self.up_left,
+            self.down_left,
+        ]
+        .iter()
+        .any(|dest| dest.is_moveable())
     }
 
     pub(crate) fn moveable_directions(&self) -> BTreeSet<Direction> {
@@ -347,34 +566,60 @@ impl MovingRange {
 
 #[cfg(test)]
 mod board_spec {
-    use super::Board;
+    use super::{Board, Direction};
     use crate::{
         cell::Cell,
         player::Player,
         position::{Column, Position, Row},
+        rule::RuleSet,
     };
 
     #[test]
     fn reached_goal_side() {
         let player_a = Player::new(0);
         let player_b = Player::new(1);
-        let mut board = Board::new(&player_a, &player_b);
+        let mut board = Board::new(RuleSet::default(), &player_a, &player_b);
         for (player, goal_side) in [(player_b, Row::Top), (player_a, Row::Bottom)].iter() {
             let position = Position::new(Column::LeftEdge, goal_side.clone());
-            let edge_cell = board.cell_map.get(&position).unwrap();
-            let reached_edge_cell = edge_cell.stack(player).unwrap();
-            board.cell_map.insert(position, reached_edge_cell);
-            assert!(board.is_reached_edge(player, goal_side));
+            let edge_cell = board.cell(&position).unwrap();
+            let reached_edge_cell = edge_cell
+                .stack(player, RuleSet::default().pallet_height)
+                .unwrap();
+            board.put(&position, reached_edge_cell);
+            assert!(board.is_reached_edge(player, (*goal_side).into()));
         }
     }
 
+    #[test]
+    fn cell() {
+        let player_a = Player::new(0);
+        let player_b = Player::new(1);
+        let board = Board::new(RuleSet::default(), &player_a, &player_b);
+        assert_eq!(
+            board.cell(&Position::new(Column::LeftEdge, Row::Top)),
+            Ok(Cell::new_occupied(player_a))
+        );
+        assert_eq!(
+            board.cell(&Position::new(Column::RightEdge, Row::Bottom)),
+            Ok(Cell::new_occupied(player_b))
+        );
+        assert!(board
+            .cell(&Position::new(Column::MiddleSecond, Row::MiddleSecond))
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            board.cell(&Position::at(5, 0)),
+            Err(crate::result::Error::InvalidPosition)
+        );
+    }
+
     #[test]
     fn not_reached_goal_side() {
         let player_a = Player::new(0);
         let player_b = Player::new(1);
-        let board = Board::new(&player_a, &player_b);
+        let board = Board::new(RuleSet::default(), &player_a, &player_b);
         for (player, goal_side) in [(player_b, Row::Top), (player_a, Row::Bottom)].iter() {
-            assert!(!board.is_reached_edge(player, goal_side));
+            assert!(!board.is_reached_edge(player, (*goal_side).into()));
         }
     }
 
@@ -382,8 +627,12 @@ mod board_spec {
     fn generate_initial_occupied_cells() {
         for side in [Row::Top, Row::Bottom].iter() {
             let player = Player::new(0);
-            let side_row = Board::generate_initial_occupied_cells(player.clone(), side.to_owned())
-                .collect::<Vec<(Position, Cell)>>();
+            let side_row = Board::generate_initial_occupied_cells(
+                &RuleSet::default(),
+                player.clone(),
+                side.to_owned().into(),
+            )
+            .collect::<Vec<(Position, Cell)>>();
             let expected_cells = [
                 Column::LeftEdge,
                 Column::MiddleFirst,
@@ -427,7 +676,8 @@ mod board_spec {
             })
             .collect::<Vec<(Position, Cell)>>();
             let row =
-                Board::generate_initial_empty_cells(row.clone()).collect::<Vec<(Position, Cell)>>();
+                Board::generate_initial_empty_cells(&RuleSet::default(), row.to_owned().into())
+                    .collect::<Vec<(Position, Cell)>>();
             assert_eq!(row, expected_cells);
         }
     }
@@ -438,11 +688,10 @@ mod board_spec {
 
         let player_a = Player::new(0);
         let player_b = Player::new(1);
-        let board = Board::new(&player_a, &player_b);
+        let board = Board::new(RuleSet::default(), &player_a, &player_b);
         let player_a_territory = board
             .territory(&player_a)
-            .keys()
-            .map(|k| k.clone())
+            .map(|(position, _)| position)
             .collect::<BTreeSet<Position>>();
         assert_eq!(
             player_a_territory,
@@ -459,75 +708,111 @@ mod board_spec {
         );
     }
 
+    #[test]
+    fn moveable_directions() {
+        let board = crate::board!(
+            [a a .]
+            [. b .]
+        );
+        assert_eq!(
+            board
+                .moveable_directions(&Position::at(0, 0))
+                .into_iter()
+                .collect::<Vec<Direction>>(),
+            vec![Direction::Down, Direction::DownRight]
+        );
+        assert_eq!(board.moveable_directions(&Position::at(1, 1)).len(), 5);
+        assert!(board.moveable_directions(&Position::at(3, 0)).is_empty());
+    }
+
     #[cfg(test)]
     mod is_isolated_spec {
-        use super::Board;
-
-        use crate::{
-            cell::Cell,
-            player::Player,
-            position::{Column, Position, Row},
-        };
-
-        fn cell_owned_board(pivot: &Position) -> Board {
-            let player_a = Player::new(0);
-            let player_b = Player::new(1);
-            let mut board = Board::new(&player_a, &player_b);
-            let cell = Cell::new_occupied(player_a.clone());
-            board.cell_map.insert(pivot.clone(), cell.clone());
-            board
-        }
+        use crate::position::{Column, Position, Row};
 
         #[test]
         fn enclosed_by_empty_cells() {
-            let pivot = Position::new(Column::MiddleSecond, Row::MiddleSecond);
-            let board = cell_owned_board(&pivot);
-            assert!(board.is_isolated(&pivot));
+            let board = crate::board!(
+                [a a a a a]
+                [. . . . .]
+                [. . a . .]
+                [. . . . .]
+                [. . . . .]
+                [b b b b b]
+            );
+            assert!(board.is_isolated(&Position::new(Column::MiddleSecond, Row::MiddleSecond)));
         }
 
         #[test]
         fn enclosed_by_oneself() {
-            let pivot = Position::new(Column::LeftEdge, Row::MiddleFirst);
-            let board = cell_owned_board(&pivot);
-            assert!(board.is_isolated(&pivot));
+            let board = crate::board!(
+                [a a a a a]
+                [a . . . .]
+                [. . . . .]
+                [. . . . .]
+                [. . . . .]
+                [b b b b b]
+            );
+            assert!(board.is_isolated(&Position::new(Column::LeftEdge, Row::MiddleFirst)));
         }
 
         #[test]
         fn there_is_opponent() {
-            let player_a = Player::new(0);
-            let player_b = Player::new(1);
-            let mut board = Board::new(&player_a, &player_b);
-            let pivot = Position::new(Column::LeftEdge, Row::Top);
-            assert_eq!(
-                board.cell_of(&pivot).unwrap().owner(),
-                Some(player_a.clone()),
+            let board = crate::board!(
+                [a a a a a]
+                [b . . . .]
+                [. . . . .]
+                [. . . . .]
+                [. . . . .]
+                [b b b b b]
             );
-            let adjacent = Position::new(Column::LeftEdge, Row::MiddleFirst);
-            board
-                .cell_map
-                .insert(adjacent.clone(), Cell::new_occupied(player_b.clone()));
-            assert!(!board.is_isolated(&pivot));
+            assert!(!board.is_isolated(&Position::new(Column::LeftEdge, Row::Top)));
         }
 
         #[test]
-        fn empty_cell() {
-            let player_a = Player::new(0);
-            let player_b = Player::new(1);
-            let board = Board::new(&player_a, &player_b);
+        fn buried_opponent() {
+            let board = crate::board!(
+                [a a a a a]
+                [. . . . .]
+                [. . a ba .]
+                [. . ab . .]
+                [. . . . .]
+                [b b b b b]
+            );
             let pivot = Position::new(Column::MiddleSecond, Row::MiddleSecond);
+            assert!(!board.is_isolated(&pivot));
+            let board = crate::board!(
+                [a a a a a]
+                [. . . . .]
+                [. . a ba .]
+                [. . . . .]
+                [. . . . .]
+                [b b b b b]
+            );
             assert!(board.is_isolated(&pivot));
         }
+
+        #[test]
+        fn empty_cell() {
+            let board = crate::board!(
+                [a a a a a]
+                [. . . . .]
+                [. . . . .]
+                [. . . . .]
+                [. . . . .]
+                [b b b b b]
+            );
+            assert!(board.is_isolated(&Position::new(Column::MiddleSecond, Row::MiddleSecond)));
+        }
     }
 
     #[test]
     fn iterate() {
         let player_a = Player::new(0);
         let player_b = Player::new(1);
-        let board = Board::new(&player_a, &player_b);
+        let board = Board::new(RuleSet::default(), &player_a, &player_b);
         let iterated = board.iterate();
-        let expected = Row::iterator().flat_map(|row| {
-            Column::iterator().map(move |col| Position::new(col, row))
-        });
+        let expected = Row::iterator()
+            .flat_map(|row| Column::iterator().map(move |col| Position::new(col, row)));
         iterated.zip(expected).for_each(|(a, e)| {
             assert_eq!(a, e);
         });
@@ -538,11 +823,12 @@ mod board_spec {
 mod moving_range_spec {
     use std::collections::BTreeSet;
 
-    use super::{CellMap, DestinationState, Direction, MovingRange, Point};
+    use super::{Board, CellMap, DestinationState, Direction, MovingRange, Point};
     use crate::{
         cell::Cell,
         player::Player,
         position::{Column, Position, Row},
+        rule::RuleSet,
     };
 
     #[test]
@@ -553,19 +839,19 @@ mod moving_range_spec {
         let pivot_cell = Cell::new_occupied(player_a.clone());
         let opponents_position = pivot_position.above().unwrap();
         let opponents_cell = Cell::new_occupied(player_b.clone());
-        let owned_position = pivot_position.below().unwrap();
+        let owned_position = pivot_position.below(&RuleSet::default()).unwrap();
         let owned_cell = Cell::new_occupied(player_a.clone());
-        let robbed_position = pivot_position.righthand().unwrap();
+        let robbed_position = pivot_position.righthand(&RuleSet::default()).unwrap();
         let robbed_cell = Cell::new_occupied(player_a.clone())
-            .stack(&player_b)
+            .stack(&player_b, RuleSet::default().pallet_height)
             .unwrap();
-        let fullfilled_position = pivot_position.above_righthand().unwrap();
+        let fullfilled_position = pivot_position.above_righthand(&RuleSet::default()).unwrap();
         let fullfilled_cell = Cell::new_occupied(player_b.clone())
-            .stack(&player_a)
+            .stack(&player_a, RuleSet::default().pallet_height)
             .unwrap()
-            .stack(&player_b)
+            .stack(&player_b, RuleSet::default().pallet_height)
             .unwrap();
-        let empty_position = pivot_position.below_righthand().unwrap();
+        let empty_position = pivot_position.below_righthand(&RuleSet::default()).unwrap();
         let empty_cell = Cell::new_empty();
         let cell_map = [
             (pivot_position.clone(), pivot_cell.clone()),
@@ -580,7 +866,10 @@ mod moving_range_spec {
             acc.insert(position.clone(), cell.clone());
             acc
         });
-        let result = MovingRange::new(&pivot_position, &cell_map);
+        let result = MovingRange::new(
+            &pivot_position,
+            &Board::from_cell_map(RuleSet::default(), cell_map),
+        );
         assert_eq!(
             result,
             Ok(MovingRange {
@@ -619,9 +908,12 @@ mod moving_range_spec {
         let mr = MovingRange {
             pivot: Point::new(position.clone(), cell.clone()),
             up: DestinationState::Moveable(Point::new(position.above().unwrap(), cell.clone())),
-            down: DestinationState::Moveable(Point::new(position.below().unwrap(), cell.clone())),
+            down: DestinationState::Moveable(Point::new(
+                position.below(&RuleSet::default()).unwrap(),
+                cell.clone(),
+            )),
             right: DestinationState::Moveable(Point::new(
-                position.righthand().unwrap(),
+                position.righthand(&RuleSet::default()).unwrap(),
                 cell.clone(),
             )),
             left: DestinationState::Moveable(Point::new(
@@ -629,11 +921,11 @@ mod moving_range_spec {
                 cell.clone(),
             )),
             up_right: DestinationState::Moveable(Point::new(
-                position.above_righthand().unwrap(),
+                position.above_righthand(&RuleSet::default()).unwrap(),
                 cell.clone(),
             )),
             down_right: DestinationState::Moveable(Point::new(
-                position.below_righthand().unwrap(),
+                position.below_righthand(&RuleSet::default()).unwrap(),
                 cell.clone(),
             )),
             up_left: DestinationState::Moveable(Point::new(
@@ -641,7 +933,7 @@ mod moving_range_spec {
                 cell.clone(),
             )),
             down_left: DestinationState::Moveable(Point::new(
-                position.below_lefthand().unwrap(),
+                position.below_lefthand(&RuleSet::default()).unwrap(),
                 cell.clone(),
             )),
         };
@@ -659,6 +951,7 @@ mod moving_range_spec {
         {
             assert!(mr.indicate(direction).is_ok());
         }
+        assert!(mr.is_moveable());
     }
 
     #[test]
@@ -688,6 +981,7 @@ mod moving_range_spec {
         {
             assert!(mr.indicate(direction).is_err());
         }
+        assert!(!mr.is_moveable());
     }
 
     #[test]
@@ -713,7 +1007,10 @@ mod moving_range_spec {
             acc
         });
         cell_map.insert(pivot_position.clone(), pivot_cell.clone());
-        let mr = MovingRange::new(&pivot_position, &cell_map);
+        let mr = MovingRange::new(
+            &pivot_position,
+            &Board::from_cell_map(RuleSet::default(), cell_map),
+        );
         assert!(mr.is_ok());
         assert_eq!(
             mr.unwrap().moveable_directions(),
@@ -731,25 +1028,72 @@ mod moving_range_spec {
     }
 }
 
+#[cfg(test)]
+mod direction_spec {
+    use super::Direction;
+    use crate::{
+        position::{Column, Position, Row},
+        result::Error,
+        rule::RuleSet,
+    };
+
+    #[test]
+    fn notation() {
+        let symbols = ["N", "S", "E", "W", "NE", "SE", "NW", "SW"];
+        for (direction, symbol) in Direction::iterator().zip(symbols.iter()) {
+            assert_eq!(&direction.to_string(), symbol);
+            assert_eq!(symbol.parse::<Direction>(), Ok(direction));
+        }
+        for invalid in ["", "n", "NN", "EN", "Up"].iter() {
+            assert_eq!(invalid.parse::<Direction>(), Err(Error::InvalidNotation));
+        }
+    }
+
+    #[test]
+    fn between() {
+        let rule_set = RuleSet::default();
+        let from = Position::new(Column::MiddleSecond, Row::MiddleSecond);
+        for direction in Direction::iterator() {
+            let to = direction.destination(&from, &rule_set).unwrap();
+            assert_eq!(Direction::between(&from, &to), Ok(direction));
+        }
+        assert_eq!(
+            Direction::between(&from, &from),
+            Err(Error::NotAdjacent(from, from))
+        );
+        let far = Position::new(Column::RightEdge, Row::Bottom);
+        assert_eq!(
+            Direction::between(&from, &far),
+            Err(Error::NotAdjacent(from, far))
+        );
+    }
+}
+
 #[cfg(test)]
 mod migrate_spec {
-    use super::Board;
+    use super::{Board, CellMap};
     use crate::{
         player::Player,
         position::{Column, Position, Row},
         result::Error,
+        rule::RuleSet,
     };
 
     #[test]
     fn migrate() {
         let player_a = Player::new(0);
         let player_b = Player::new(1);
-        let board = Board::new(&player_a, &player_b);
+        let board = Board::new(RuleSet::default(), &player_a, &player_b);
         let from_position = Position::new(Column::LeftEdge, Row::Top);
-        let to_position = from_position.below().unwrap();
-        let migrated = board.migrate(&from_position, &to_position);
-        assert!(migrated.is_ok());
-        let migrated_territory = migrated.unwrap().territory(&player_a);
+        let to_position = from_position.below(&RuleSet::default()).unwrap();
+        let mut migrated = board.clone();
+        assert!(migrated.migrate(&from_position, &to_position).is_ok());
+        assert_eq!(
+            migrated.hash(),
+            Board::from_cell_map(RuleSet::default(), migrated.cell_map()).hash()
+        );
+        assert_ne!(migrated.hash(), board.hash());
+        let migrated_territory = migrated.territory(&player_a).collect::<CellMap>();
         let migrated_from_cell = migrated_territory.get(&from_position);
         assert!(migrated_from_cell.is_none());
         let migrated_to_cell = migrated_territory.get(&to_position).unwrap();
@@ -760,7 +1104,7 @@ mod migrate_spec {
     fn empty_cell_cannot_migrate() {
         let player_a = Player::new(0);
         let player_b = Player::new(1);
-        let board = Board::new(&player_a, &player_b);
+        let mut board = Board::new(RuleSet::default(), &player_a, &player_b);
         let migrated = board.migrate(
             &Position::new(Column::MiddleFirst, Row::MiddleFirst),
             &Position::new(Column::MiddleFirst, Row::MiddleSecond),
@@ -771,22 +1115,20 @@ mod migrate_spec {
     fn fullfilled_cell_cannot_migrate() {
         let player_a = Player::new(0);
         let player_b = Player::new(1);
-        let mut board = Board::new(&player_a, &player_b);
+        let mut board = Board::new(RuleSet::default(), &player_a, &player_b);
         let from_position = Position::new(Column::LeftEdge, Row::Top);
-        let to_position = from_position.below().unwrap();
+        let to_position = from_position.below(&RuleSet::default()).unwrap();
         let fullfilled_cell = board
-            .cell_of(&to_position)
+            .cell(&to_position)
             .unwrap()
-            .stack(&player_b)
+            .stack(&player_b, RuleSet::default().pallet_height)
             .unwrap()
-            .stack(&player_a)
+            .stack(&player_a, RuleSet::default().pallet_height)
             .unwrap()
-            .stack(&player_b)
+            .stack(&player_b, RuleSet::default().pallet_height)
             .unwrap();
 
-        board
-            .cell_map
-            .insert(to_position.clone(), fullfilled_cell.clone());
+        board.put(&to_position, fullfilled_cell);
         assert_eq!(
             board.migrate(&from_position, &to_position),
             Err(Error::CellIsFullfilled)
@@ -797,16 +1139,14 @@ mod migrate_spec {
     fn already_occupied_cell_cannot_migrate() {
         let player_a = Player::new(0);
         let player_b = Player::new(1);
-        let mut board = Board::new(&player_a, &player_b);
+        let mut board = Board::new(RuleSet::default(), &player_a, &player_b);
         let from_position = Position::new(Column::MiddleFirst, Row::Top);
         let to_position = from_position.lefthand().unwrap();
-        let already_occupied_cell = board.cell_of(&to_position).unwrap();
-        board
-            .cell_map
-            .insert(to_position.clone(), already_occupied_cell.clone());
+        let already_occupied_cell = board.cell(&to_position).unwrap();
+        board.put(&to_position, already_occupied_cell);
         assert_eq!(
             board.migrate(&from_position, &to_position),
-            Err(Error::AlreadyOccupied(player_a.clone()))
+            Err(Error::AlreadyOccupied(to_position, player_a.clone()))
         );
     }
 }
@@ -0,0 +1,219 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    board::{Board, CellMap},
+    cell::Cell,
+    player::Player,
+    position::Position,
+    result::{Error, Result},
+    rule::RuleSet,
+    Game,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoardBuilder {
+    rule_set: RuleSet,
+    pallets: BTreeMap<Position, Vec<Option<Player>>>,
+    side: Player,
+}
+
+impl BoardBuilder {
+    pub fn new(rule_set: RuleSet) -> Self {
+        Self {
+            rule_set,
+            pallets: BTreeMap::new(),
+            side: Player::new(0),
+        }
+    }
+
+    pub fn place(self, position: Position, stack: &[Player]) -> Self {
+        let pallet = stack
+            .iter()
+            .copied()
+            .map(Some)
+            .collect::<Vec<Option<Player>>>();
+        self.place_pallet(position, &pallet)
+    }
+
+    pub fn place_pallet(mut self, position: Position, pallet: &[Option<Player>]) -> Self {
+        self.pallets.insert(position, pallet.to_vec());
+        self
+    }
+
+    pub fn clear(mut self, position: Position) -> Self {
+        self.pallets.remove(&position);
+        self
+    }
+
+    pub fn side_to_move(mut self, player: Player) -> Self {
+        self.side = player;
+        self
+    }
+
+    pub fn build(&self) -> Result<Game> {
+        self.rule_set.validate()?;
+        Self::verify_player(&self.side)?;
+        let mut cell_map = CellMap::new();
+        let mut pieces = [0; 2];
+        for (position, pallet) in self.pallets.iter() {
+            if !position.is_inside(&self.rule_set) {
+                return Err(Error::InvalidPosition);
+            }
+            let cell = self.build_cell(pallet)?;
+            cell.players().for_each(|player| pieces[player.id] += 1);
+            cell_map.insert(*position, cell);
+        }
+        if let Some(id) = (0..pieces.len()).find(|id| pieces[*id] != self.rule_set.columns) {
+            return Err(Error::InvalidPieceCount(Player::new(id)));
+        }
+        Ok(Game::from_board(
+            Board::from_cell_map(self.rule_set, cell_map),
+            self.side,
+        ))
+    }
+
+    fn build_cell(&self, pallet: &[Option<Player>]) -> Result<Cell> {
+        let mut cell = Cell::new_empty();
+        let mut has_gap = false;
+        for level in pallet.iter() {
+            match level {
+                None => has_gap = true,
+                Some(_) if has_gap => return Err(Error::PalletHasGap),
+                Some(player) => {
+                    Self::verify_player(player)?;
+                    cell = cell.stack(player, self.rule_set.pallet_height)?;
+                }
+            }
+        }
+        Ok(cell)
+    }
+
+    fn verify_player(player: &Player) -> Result<()> {
+        if player.id < 2 {
+            Ok(())
+        } else {
+            Err(Error::InvalidPlayer)
+        }
+    }
+}
+
+impl From<&Game> for BoardBuilder {
+    fn from(game: &Game) -> Self {
+        let builder = Self::new(*game.rule_set()).side_to_move(game.current_player());
+        game.board
+            .iterate()
+            .filter_map(|position| {
+                let cell = game.board.cell(&position).unwrap();
+                if cell.is_empty() {
+                    None
+                } else {
+                    Some((position, cell.pallet()))
+                }
+            })
+            .fold(builder, |builder, (position, pallet)| {
+                builder.place_pallet(position, &pallet)
+            })
+    }
+}
+
+#[cfg(test)]
+mod builder_spec {
+    use super::BoardBuilder;
+    use crate::{player::Player, position::Position, result::Error, rule::RuleSet, Game};
+
+    fn players() -> (Player, Player) {
+        (Player::new(0), Player::new(1))
+    }
+
+    fn small() -> RuleSet {
+        RuleSet {
+            columns: 2,
+            rows: 3,
+            ..RuleSet::default()
+        }
+    }
+
+    #[test]
+    fn build_position() {
+        let (a, b) = players();
+        let game = BoardBuilder::new(small())
+            .place(Position::at(0, 0), &[a, b])
+            .place(Position::at(1, 2), &[b])
+            .place(Position::at(1, 1), &[a])
+            .side_to_move(b)
+            .build()
+            .unwrap();
+        assert_eq!(game.to_fen(), "(ab)1/1a/1b b");
+        assert_eq!(game.current_player(), b);
+        assert_eq!(game.ply(), 0);
+        assert!(!game.is_over());
+    }
+
+    #[test]
+    fn from_game() {
+        let game = Game::default();
+        assert_eq!(BoardBuilder::from(&game).build(), Ok(game.clone()));
+        let game = game.play(&["a1-S", "b6-N"]);
+        let (a, _) = players();
+        let edited = BoardBuilder::from(&game)
+            .clear(Position::at(0, 1))
+            .place(Position::at(4, 2), &[a])
+            .build()
+            .unwrap();
+        assert_eq!(edited.to_fen(), "1aaaa/5/4a/5/1b3/b1bbb a");
+    }
+
+    #[test]
+    fn invalid_position() {
+        let (a, b) = players();
+        let builder = BoardBuilder::new(small())
+            .place(Position::at(0, 0), &[a])
+            .place(Position::at(1, 0), &[a])
+            .place(Position::at(0, 2), &[b])
+            .place(Position::at(1, 2), &[b]);
+        assert!(builder.build().is_ok());
+        let cases = vec![
+            (
+                BoardBuilder::new(RuleSet { rows: 1, ..small() }),
+                Error::InvalidRuleSet,
+            ),
+            (
+                builder.clone().place(Position::at(2, 0), &[]),
+                Error::InvalidPosition,
+            ),
+            (
+                builder.clone().side_to_move(Player::new(2)),
+                Error::InvalidPlayer,
+            ),
+            (
+                builder.clone().place(Position::at(0, 1), &[Player::new(2)]),
+                Error::InvalidPlayer,
+            ),
+            (
+                builder
+                    .clone()
+                    .place_pallet(Position::at(1, 0), &[Some(b), None, Some(a)]),
+                Error::PalletHasGap,
+            ),
+            (
+                builder.clone().place(Position::at(1, 0), &[a, a]),
+                Error::StackedOnOwnPiece(a),
+            ),
+            (
+                builder.clone().place(Position::at(1, 0), &[a, b, a, b]),
+                Error::ReachedPalletHeightLimit,
+            ),
+            (
+                builder.clone().clear(Position::at(1, 0)),
+                Error::InvalidPieceCount(a),
+            ),
+            (
+                builder.clone().place(Position::at(0, 1), &[b]),
+                Error::InvalidPieceCount(b),
+            ),
+        ];
+        for (builder, error) in cases.into_iter() {
+            assert_eq!(builder.build(), Err(error), "{:?}", builder);
+        }
+    }
+}
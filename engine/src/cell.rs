@@ -3,23 +3,48 @@ use crate::{
     result::{Error, Result},
 };
 
-pub(crate) const PALLET_HEIGHT_LIMIT: usize = 3;
+pub const MAX_PALLET_HEIGHT: usize = 8;
 
+// A stack is at most MAX_PALLET_HEIGHT pieces of two players, so it packs into
+// its height plus one bit per level telling whose piece sits there. That keeps
+// the cell two bytes and the board cheap to copy.
+/// With the `serde` feature a cell is written as the list of player ids in its
+/// stack from bottom to top, e.g. `[0, 1]`; an empty cell is `[]`. Reading
+/// rejects unknown players, two pieces of one player on top of each other and
+/// stacks higher than `MAX_PALLET_HEIGHT`.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "Vec<Player>", try_from = "Vec<Player>")
+)]
 pub struct Cell {
-    pub(crate) pallet: [Option<Player>; PALLET_HEIGHT_LIMIT],
+    height: u8,
+    owners: u8,
 }
 
 impl Cell {
-    fn height(&self) -> usize {
-        self.pallet.iter().filter(|x| x.is_some()).count()
+    pub fn height(&self) -> usize {
+        self.height as usize
+    }
+
+    pub(crate) fn pallet(&self) -> [Option<Player>; MAX_PALLET_HEIGHT] {
+        let mut pallet = [None; MAX_PALLET_HEIGHT];
+        for (level, player) in pallet.iter_mut().enumerate().take(self.height()) {
+            *player = Some(self.player_at(level));
+        }
+        pallet
+    }
+
+    pub fn players(&self) -> impl Iterator<Item = Player> + '_ {
+        (0..self.height()).map(move |level| self.player_at(level))
     }
 
     pub fn owner(&self) -> Option<Player> {
         if self.is_empty() {
             None
         } else {
-            self.pallet[self.height() - 1].clone()
+            Some(self.player_at(self.height() - 1))
         }
     }
 
@@ -30,39 +55,36 @@ impl Cell {
         }
     }
 
-    pub(crate) fn is_empty(&self) -> bool {
-        self.height() == 0
+    pub fn is_empty(&self) -> bool {
+        self.height == 0
     }
 
-    pub(crate) fn is_fullfilled(&self) -> bool {
-        self.height() == PALLET_HEIGHT_LIMIT
+    pub(crate) fn is_fullfilled(&self, height_limit: usize) -> bool {
+        self.height() >= height_limit
     }
 
     pub(crate) fn new_empty() -> Self {
         Self {
-            pallet: [None; PALLET_HEIGHT_LIMIT],
+            height: 0,
+            owners: 0,
         }
     }
 
     pub(crate) fn new_occupied(player: Player) -> Self {
-        Self {
-            pallet: [Some(player), None, None],
-        }
+        Self::new_empty().stack(&player, 1).unwrap()
     }
 
-    pub(crate) fn stack(&self, player: &Player) -> Result<Self> {
-        let height = self.height();
-        if height == PALLET_HEIGHT_LIMIT {
+    pub(crate) fn stack(&self, player: &Player, height_limit: usize) -> Result<Self> {
+        if self.is_fullfilled(height_limit) {
             Err(Error::ReachedPalletHeightLimit)
+        } else if self.owner() == Some(*player) {
+            Err(Error::StackedOnOwnPiece(*player))
         } else {
-            let owner = self.owner();
-            if owner == Some(player.clone()) {
-                Err(Error::AlreadyOccupied(player.clone()))
-            } else {
-                let mut players = self.pallet;
-                players[height] = Some(player.clone());
-                Ok(Self { pallet: players })
-            }
+            debug_assert!(player.id < 2);
+            Ok(Self {
+                height: self.height + 1,
+                owners: self.owners | (player.id as u8) << self.height,
+            })
         }
     }
 
@@ -70,28 +92,56 @@ impl Cell {
         if self.is_empty() {
             Err(Error::CellIsEmpty)
         } else {
-            let mut players = self.pallet;
-            players[self.height() - 1] = None;
-            Ok(Self { pallet: players })
+            let height = self.height - 1;
+            Ok(Self {
+                height,
+                owners: self.owners & !(1 << height),
+            })
         }
     }
+
+    fn player_at(&self, level: usize) -> Player {
+        Player::new((self.owners >> level & 1) as usize)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Cell> for Vec<Player> {
+    fn from(cell: Cell) -> Self {
+        cell.players().collect()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<Vec<Player>> for Cell {
+    type Error = &'static str;
+
+    fn try_from(players: Vec<Player>) -> std::result::Result<Self, Self::Error> {
+        players.iter().try_fold(Self::new_empty(), |cell, player| {
+            if player.id >= 2 {
+                return Err("player id is out of range");
+            }
+            cell.stack(player, MAX_PALLET_HEIGHT)
+                .map_err(|error| match error {
+                    Error::StackedOnOwnPiece(_) => "piece is stacked on its own piece",
+                    _ => "stack is too high",
+                })
+        })
+    }
 }
 
 #[cfg(test)]
 mod cell_spec {
-    use super::{Cell, PALLET_HEIGHT_LIMIT};
+    use super::{Cell, MAX_PALLET_HEIGHT};
     use crate::{player::Player, result::Error};
 
+    const HEIGHT_LIMIT: usize = 3;
+
     #[test]
     fn new_occupied() {
         let player = Player::new(0);
         let cell = Cell::new_occupied(player);
-        assert_eq!(
-            cell,
-            Cell {
-                pallet: [Some(player), None, None]
-            },
-        );
+        assert_eq!(&cell.pallet()[..3], &[Some(player), None, None]);
         assert_eq!(cell.owner(), Some(player));
         assert_eq!(cell.height(), 1);
         assert!(!cell.is_empty());
@@ -103,7 +153,8 @@ mod cell_spec {
         assert_eq!(
             cell,
             Cell {
-                pallet: [None; PALLET_HEIGHT_LIMIT],
+                height: 0,
+                owners: 0,
             },
         );
         assert!(cell.is_empty());
@@ -114,31 +165,50 @@ mod cell_spec {
     fn stack() {
         let player_1 = Player::new(0);
         let cell = Cell::new_empty();
-        let first_stacked = cell.stack(&player_1);
+        let first_stacked = cell.stack(&player_1, HEIGHT_LIMIT);
         assert!(first_stacked.is_ok());
         let cell_has_one_player = first_stacked.unwrap();
         let over_stacking_cell = cell_has_one_player;
         assert_eq!(
-            over_stacking_cell.stack(&player_1),
-            Err(Error::AlreadyOccupied(player_1)),
+            over_stacking_cell.stack(&player_1, HEIGHT_LIMIT),
+            Err(Error::StackedOnOwnPiece(player_1)),
         );
         assert_eq!(cell_has_one_player.height(), 1);
         assert_eq!(cell_has_one_player.owner(), Some(player_1));
         let player_2 = Player::new(1);
-        let second_stacked = cell_has_one_player.stack(&player_2);
+        let second_stacked = cell_has_one_player.stack(&player_2, HEIGHT_LIMIT);
         assert!(second_stacked.is_ok());
         let cell_has_two_players = second_stacked.unwrap();
         assert_eq!(
-            &cell_has_two_players.pallet,
+            &cell_has_two_players.pallet()[..3],
             &[Some(player_1), Some(player_2), None]
         );
         let stacking_error = cell_has_two_players
-            .stack(&Player::new(2))
+            .stack(&player_1, HEIGHT_LIMIT)
             .unwrap()
-            .stack(&Player::new(3));
+            .stack(&player_2, HEIGHT_LIMIT);
         assert_eq!(stacking_error, Err(Error::ReachedPalletHeightLimit));
     }
 
+    #[test]
+    fn players() {
+        let (player_a, player_b) = (Player::new(0), Player::new(1));
+        assert_eq!(Cell::new_empty().players().count(), 0);
+        let cell = Cell::new_occupied(player_a)
+            .stack(&player_b, HEIGHT_LIMIT)
+            .unwrap()
+            .stack(&player_a, HEIGHT_LIMIT)
+            .unwrap();
+        assert_eq!(
+            cell.players().collect::<Vec<Player>>(),
+            vec![player_a, player_b, player_a]
+        );
+        assert_eq!(
+            cell.unstack().unwrap().players().collect::<Vec<Player>>(),
+            vec![player_a, player_b]
+        );
+    }
+
     #[test]
     fn unstack() {
         let player_1 = Player::new(0);
@@ -147,7 +217,8 @@ mod cell_spec {
         assert_eq!(
             unstacked,
             Ok(Cell {
-                pallet: [None; PALLET_HEIGHT_LIMIT]
+                height: 0,
+                owners: 0,
             }),
         );
         let empty_cell = unstacked.unwrap();
@@ -161,11 +232,33 @@ mod cell_spec {
         let player_b = Player::new(1);
         let cell = Cell::new_occupied(player_a);
         assert!(cell
-            .stack(&player_b)
+            .stack(&player_b, HEIGHT_LIMIT)
+            .unwrap()
+            .stack(&player_a, HEIGHT_LIMIT)
+            .unwrap()
+            .is_fullfilled(HEIGHT_LIMIT))
+    }
+
+    #[test]
+    fn configurable_height_limit() {
+        let player_a = Player::new(0);
+        let player_b = Player::new(1);
+        let cell = Cell::new_occupied(player_a).stack(&player_b, 2).unwrap();
+        assert!(cell.is_fullfilled(2));
+        assert_eq!(
+            cell.stack(&player_a, 2),
+            Err(Error::ReachedPalletHeightLimit)
+        );
+        let cell = cell
+            .stack(&player_a, 5)
             .unwrap()
-            .stack(&player_a)
+            .stack(&player_b, 5)
             .unwrap()
-            .is_fullfilled())
+            .stack(&player_a, 5)
+            .unwrap();
+        assert_eq!(cell.height(), 5);
+        assert!(cell.is_fullfilled(5));
+        assert!(!cell.is_fullfilled(MAX_PALLET_HEIGHT));
     }
 
     #[cfg(test)]
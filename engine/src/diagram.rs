@@ -0,0 +1,277 @@
+use std::fmt;
+
+use crate::{
+    board::Board,
+    fen::{self, player_of, player_symbol},
+    player::Player,
+    position::{ColumnSymbol, Position},
+    result::{Error, Result},
+    rule::RuleSet,
+};
+
+const EMPTY: char = '.';
+
+pub(crate) fn write_board(board: &Board, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let RuleSet {
+        columns,
+        rows,
+        pallet_height,
+        ..
+    } = *board.rule_set();
+    let label_width = rows.to_string().len() + 1;
+    let cell_width = pallet_height + 1;
+    let header = (0..columns)
+        .map(|x| format!("{:<width$}", ColumnSymbol(x), width = cell_width))
+        .collect::<String>();
+    write!(f, "{}{}", " ".repeat(label_width), header.trim_end())?;
+    for y in 0..rows {
+        let row = (0..columns)
+            .map(|x| {
+                let cell = board.cell(&Position::at(x, y)).unwrap();
+                let stack = if cell.is_empty() {
+                    EMPTY.to_string()
+                } else {
+                    cell.players()
+                        .map(|player| player_symbol(&player))
+                        .collect()
+                };
+                format!("{:<width$}", stack, width = cell_width)
+            })
+            .collect::<String>();
+        write!(
+            f,
+            "\n{:<width$}{}",
+            y + 1,
+            row.trim_end(),
+            width = label_width
+        )?;
+    }
+    Ok(())
+}
+
+pub(crate) fn parse(diagram: &str, rule_set: &RuleSet) -> Result<(Board, Player)> {
+    let lines = diagram
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<&str>>();
+    if lines.len() < 2 {
+        return Err(Error::InvalidNotation);
+    }
+    let (header, lines) = lines.split_first().unwrap();
+    let (side, lines) = lines.split_last().unwrap();
+    parse_header(header, rule_set)?;
+    let rows = lines
+        .iter()
+        .enumerate()
+        .map(|(y, line)| parse_row(line, y))
+        .collect::<Result<Vec<String>>>()?;
+    let side = match side.split_whitespace().collect::<Vec<&str>>()[..] {
+        [side, "to", "move"] => side,
+        _ => return Err(Error::InvalidNotation),
+    };
+    fen::parse(&format!("{} {}", rows.join("/"), side), rule_set)
+}
+
+fn parse_header(header: &str, rule_set: &RuleSet) -> Result<()> {
+    let symbols = header.split_whitespace().collect::<Vec<&str>>();
+    if symbols.len() != rule_set.columns {
+        return Err(Error::InvalidBoardSize);
+    }
+    if symbols
+        .iter()
+        .enumerate()
+        .all(|(x, symbol)| *symbol == ColumnSymbol(x).to_string())
+    {
+        Ok(())
+    } else {
+        Err(Error::InvalidNotation)
+    }
+}
+
+fn parse_row(line: &str, y: usize) -> Result<String> {
+    let mut tokens = line.split_whitespace();
+    if tokens.next() != Some(&(y + 1).to_string()) {
+        return Err(Error::InvalidNotation);
+    }
+    fen_row(tokens)
+}
+
+pub(crate) fn fen_row<'a>(tokens: impl Iterator<Item = &'a str>) -> Result<String> {
+    let mut row = String::new();
+    let mut empty_cells = 0;
+    for token in tokens {
+        if token.chars().all(|c| c == EMPTY) {
+            empty_cells += token.len();
+            continue;
+        }
+        if !token.chars().all(|c| player_of(c).is_some()) {
+            return Err(Error::InvalidNotation);
+        }
+        if empty_cells > 0 {
+            row.push_str(&empty_cells.to_string());
+            empty_cells = 0;
+        }
+        if token.len() == 1 {
+            row.push_str(token);
+        } else {
+            row.push_str(&format!("({})", token));
+        }
+    }
+    if empty_cells > 0 {
+        row.push_str(&empty_cells.to_string());
+    }
+    Ok(row)
+}
+
+#[cfg(test)]
+mod diagram_spec {
+    use super::parse;
+    use crate::{
+        fixture::OPENING,
+        result::Error,
+        rule::{RuleSet, Stalemate},
+        Action, Game,
+    };
+
+    const INITIAL: &str = "  a   b   c   d   e
+1 a   a   a   a   a
+2 .   .   .   .   .
+3 .   .   .   .   .
+4 .   .   .   .   .
+5 .   .   .   .   .
+6 b   b   b   b   b";
+
+    #[test]
+    fn display_initial() {
+        let game = Game::default();
+        assert_eq!(game.board.to_string(), INITIAL);
+        assert_eq!(game.to_string(), format!("{}\na to move", INITIAL));
+    }
+
+    #[test]
+    fn display_stacks() {
+        let game = Game::from_fen("3(aba)b/3(aba)(ab)/a4/5/5/5 b", RuleSet::default()).unwrap();
+        assert_eq!(
+            game.to_string(),
+            "  a   b   c   d   e
+1 .   .   .   aba b
+2 .   .   .   aba ab
+3 a   .   .   .   .
+4 .   .   .   .   .
+5 .   .   .   .   .
+6 .   .   .   .   .
+b to move"
+        );
+        let rule_set = RuleSet {
+            columns: 3,
+            rows: 10,
+            pallet_height: 2,
+            ..RuleSet::default()
+        };
+        assert_eq!(
+            Game::new(rule_set).unwrap().board.to_string(),
+            "   a  b  c
+1  a  a  a
+2  .  .  .
+3  .  .  .
+4  .  .  .
+5  .  .  .
+6  .  .  .
+7  .  .  .
+8  .  .  .
+9  .  .  .
+10 b  b  b"
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for ply in 1..=OPENING.len() {
+            let game = Game::default().play(&OPENING[..ply]);
+            assert_eq!(
+                parse(&game.to_string(), game.rule_set()),
+                Ok((game.board.clone(), game.current_player())),
+            );
+        }
+    }
+
+    #[test]
+    fn parse_picture() {
+        let game = Game::from_diagram(
+            "
+               a   b   c   d   e
+            1  .   .   .   aba b
+            2  .   .   .   aba aba
+            3  a   .   .   .   .
+            4  .   .   .   .   .
+            5  .   .   .   .   .
+            6  .   .   .   .   .
+            a to move
+            ",
+            RuleSet {
+                stalemate: Stalemate::Draw,
+                ..RuleSet::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(game.to_fen(), "3(aba)b/3(aba)(aba)/a4/5/5/5 a");
+        let game = game.accept(&"a3-S".parse::<Action>().unwrap()).unwrap();
+        assert!(game.is_draw());
+    }
+
+    fn with_side(board: &str) -> String {
+        format!("{}\na to move", board)
+    }
+
+    #[test]
+    fn invalid_diagram() {
+        let rule_set = RuleSet::default();
+        let cases = vec![
+            (String::new(), Error::InvalidNotation),
+            (INITIAL.to_string(), Error::InvalidNotation),
+            (
+                with_side(&INITIAL.replace("  a   b", "  b   a")),
+                Error::InvalidNotation,
+            ),
+            (
+                with_side(&INITIAL.replace("  a   b", "  b")),
+                Error::InvalidBoardSize,
+            ),
+            (
+                with_side(&INITIAL.replace("2 .", "3 .")),
+                Error::InvalidNotation,
+            ),
+            (
+                with_side(&INITIAL.replace("2 .", "2 c")),
+                Error::InvalidNotation,
+            ),
+            (
+                with_side(&INITIAL.replace("2 .", "2 (a)")),
+                Error::InvalidNotation,
+            ),
+            (
+                with_side(&INITIAL.replace("2 .", "2 . .")),
+                Error::InvalidBoardSize,
+            ),
+            (
+                with_side(&INITIAL.replace("2 .", "2 abab")),
+                Error::ReachedPalletHeightLimit,
+            ),
+            (
+                with_side(&INITIAL.replace("\n6 b   b   b   b   b", "")),
+                Error::InvalidBoardSize,
+            ),
+            (format!("{}\nc to move", INITIAL), Error::InvalidNotation),
+            (format!("{}\na", INITIAL), Error::InvalidNotation),
+        ];
+        for (diagram, error) in cases.iter() {
+            assert_eq!(
+                parse(diagram, &rule_set).err().as_ref(),
+                Some(error),
+                "{}",
+                diagram
+            );
+        }
+    }
+}
@@ -0,0 +1,303 @@
+use std::iter::Peekable;
+use std::str::Chars;
+
+use crate::{
+    board::{Board, CellMap},
+    cell::Cell,
+    player::Player,
+    position::Position,
+    result::{Error, Result},
+    rule::RuleSet,
+    Game,
+};
+
+pub(crate) fn format(game: &Game) -> String {
+    let board = &game.board;
+    let rows = (0..board.rule_set().rows)
+        .map(|y| format_row(board, y))
+        .collect::<Vec<String>>()
+        .join("/");
+    format!("{} {}", rows, player_symbol(&game.current_player()))
+}
+
+fn format_row(board: &Board, y: usize) -> String {
+    let mut row = String::new();
+    let mut empty_cells = 0;
+    for x in 0..board.rule_set().columns {
+        let cell = board.cell(&Position::at(x, y)).unwrap();
+        if cell.is_empty() {
+            empty_cells += 1;
+            continue;
+        }
+        if empty_cells > 0 {
+            row.push_str(&empty_cells.to_string());
+            empty_cells = 0;
+        }
+        let stack = cell
+            .pallet()
+            .iter()
+            .flatten()
+            .map(player_symbol)
+            .collect::<String>();
+        if cell.height() == 1 {
+            row.push_str(&stack);
+        } else {
+            row.push_str(&format!("({})", stack));
+        }
+    }
+    if empty_cells > 0 {
+        row.push_str(&empty_cells.to_string());
+    }
+    row
+}
+
+pub(crate) fn parse(fen: &str, rule_set: &RuleSet) -> Result<(Board, Player)> {
+    rule_set.validate()?;
+    let mut fields = fen.split_whitespace();
+    let (rows, side) = match (fields.next(), fields.next(), fields.next()) {
+        (Some(rows), Some(side), None) => (rows, side),
+        _ => return Err(Error::InvalidNotation),
+    };
+    let rows = rows.split('/').collect::<Vec<&str>>();
+    if rows.len() != rule_set.rows {
+        return Err(Error::InvalidBoardSize);
+    }
+    let mut cell_map = CellMap::new();
+    for (y, row) in rows.iter().enumerate() {
+        let cells = parse_row(row, rule_set)?;
+        if cells.len() != rule_set.columns {
+            return Err(Error::InvalidBoardSize);
+        }
+        cells.into_iter().enumerate().for_each(|(x, cell)| {
+            cell_map.insert(Position::at(x, y), cell);
+        });
+    }
+    let mut side = side.chars();
+    let player = match (side.next().and_then(player_of), side.next()) {
+        (Some(player), None) => player,
+        _ => return Err(Error::InvalidNotation),
+    };
+    Ok((Board::from_cell_map(*rule_set, cell_map), player))
+}
+
+fn parse_row(row: &str, rule_set: &RuleSet) -> Result<Vec<Cell>> {
+    let mut cells = Vec::new();
+    let mut chars = row.chars().peekable();
+    while let Some(c) = chars.next() {
+        if let Some(digit) = c.to_digit(10) {
+            let mut empty_cells = digit as usize;
+            while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+                empty_cells = empty_cells * 10 + digit as usize;
+                if cells.len() + empty_cells > rule_set.columns {
+                    return Err(Error::InvalidBoardSize);
+                }
+                chars.next();
+            }
+            if empty_cells == 0 || cells.len() + empty_cells > rule_set.columns {
+                return Err(Error::InvalidBoardSize);
+            }
+            cells.extend((0..empty_cells).map(|_| Cell::new_empty()));
+        } else if c == '(' {
+            cells.push(parse_stack(&mut chars, rule_set)?);
+        } else {
+            let player = player_of(c).ok_or(Error::InvalidNotation)?;
+            cells.push(Cell::new_occupied(player));
+        }
+    }
+    Ok(cells)
+}
+
+fn parse_stack(chars: &mut Peekable<Chars>, rule_set: &RuleSet) -> Result<Cell> {
+    let mut cell = Cell::new_empty();
+    let mut has_gap = false;
+    loop {
+        match chars.next() {
+            Some(')') if !cell.is_empty() => return Ok(cell),
+            Some('.') => has_gap = true,
+            Some(c) => {
+                let player = player_of(c).ok_or(Error::InvalidNotation)?;
+                if has_gap {
+                    return Err(Error::PalletHasGap);
+                }
+                cell = cell.stack(&player, rule_set.pallet_height)?;
+            }
+            None => return Err(Error::InvalidNotation),
+        }
+    }
+}
+
+pub(crate) fn player_symbol(player: &Player) -> char {
+    (b'a' + player.id as u8) as char
+}
+
+pub(crate) fn player_of(symbol: char) -> Option<Player> {
+    match symbol {
+        'a' => Some(Player::new(0)),
+        'b' => Some(Player::new(1)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod fen_spec {
+    use super::{format, parse};
+    use crate::{
+        board::Direction,
+        cell::Cell,
+        player::Player,
+        position::{Column, Position, Row},
+        result::Error,
+        rule::RuleSet,
+        Action, Game,
+    };
+
+    const INITIAL: &str = "aaaaa/5/5/5/5/bbbbb a";
+
+    #[test]
+    fn format_initial() {
+        assert_eq!(format(&Game::default()), INITIAL);
+    }
+
+    #[test]
+    fn format_stacks() {
+        let game = Game::default().play(&["a1-S", "b6>a5"]);
+        assert_eq!(format(&game), "1aaaa/a4/5/5/b4/b1bbb a");
+        let game = game.play(&["a2-S", "a5-N", "b1-S", "a4-N"]);
+        assert_eq!(format(&game), "2aaa/1a3/(ab)4/5/5/b1bbb a");
+    }
+
+    #[test]
+    fn parse_initial() {
+        let game = Game::default();
+        assert_eq!(
+            parse(INITIAL, &RuleSet::default()),
+            Ok((game.board.clone(), game.current_player())),
+        );
+    }
+
+    #[test]
+    fn parse_stacks() {
+        let (board, player) = parse("3(aba)b/3(aba)(ab.)/a4/5/5/5 b", &RuleSet::default()).unwrap();
+        let (player_a, player_b) = (Player::new(0), Player::new(1));
+        assert_eq!(player, player_b);
+        let stack = board
+            .cell(&Position::new(Column::MiddleThird, Row::Top))
+            .unwrap();
+        assert_eq!(
+            &stack.pallet()[..3],
+            &[Some(player_a), Some(player_b), Some(player_a)]
+        );
+        assert_eq!(
+            board.cell(&Position::new(Column::RightEdge, Row::MiddleFirst)),
+            Ok(Cell::new_occupied(player_a).stack(&player_b, 3).unwrap()),
+        );
+        assert_eq!(
+            board.cell(&Position::new(Column::LeftEdge, Row::MiddleSecond)),
+            Ok(Cell::new_occupied(player_a)),
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let actions = ["a1-S", "a6-N", "a2-S", "a5-N", "b1-SW", "e6-NW"];
+        for ply in 1..=actions.len() {
+            let game = Game::default().play(&actions[..ply]);
+            let fen = format(&game);
+            assert_eq!(
+                parse(&fen, game.rule_set()),
+                Ok((game.board.clone(), game.current_player())),
+            );
+        }
+        let rule_set = RuleSet {
+            columns: 12,
+            rows: 3,
+            ..RuleSet::default()
+        };
+        let game = Game::new(rule_set).unwrap();
+        assert_eq!(format(&game), "aaaaaaaaaaaa/12/bbbbbbbbbbbb a");
+        assert_eq!(
+            parse(&format(&game), &rule_set),
+            Ok((game.board.clone(), game.current_player())),
+        );
+    }
+
+    #[test]
+    fn invalid_syntax() {
+        for fen in [
+            "",
+            "aaaaa/5/5/5/5/bbbbb",
+            "aaaaa/5/5/5/5/bbbbb c",
+            "aaaaa/5/5/5/5/bbbbb ab",
+            "aaaaa/5/5/5/5/bbbbb a a",
+            "aaaca/5/5/5/5/bbbbb a",
+            "aaa()a/5/5/5/5/bbbbb a",
+            "aaa(ab/5/5/5/5/bbbbb a",
+        ]
+        .iter()
+        {
+            assert_eq!(
+                parse(fen, &RuleSet::default()),
+                Err(Error::InvalidNotation),
+                "{}",
+                fen
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_size() {
+        for fen in [
+            "aaaaa/5/5/5/bbbbb a",
+            "aaaaa/5/5/5/5/5/bbbbb a",
+            "aaaa/5/5/5/5/bbbbb a",
+            "aaaaa/6/5/5/5/bbbbb a",
+            "aaaaa/0/5/5/5/bbbbb a",
+            "aaaaa/5/5/5/5/bbbbbb a",
+            "99999999999999999999999/5/5/5/5/bbbbb a",
+        ]
+        .iter()
+        {
+            assert_eq!(
+                parse(fen, &RuleSet::default()),
+                Err(Error::InvalidBoardSize),
+                "{}",
+                fen
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_pallet() {
+        let rule_set = RuleSet::default();
+        assert_eq!(
+            parse("(a.b)aaaa/5/5/5/5/bbbbb a", &rule_set),
+            Err(Error::PalletHasGap),
+        );
+        assert_eq!(
+            parse("(abab)aaaa/5/5/5/5/bbbbb a", &rule_set),
+            Err(Error::ReachedPalletHeightLimit),
+        );
+        assert_eq!(
+            parse("(aab)aaaa/5/5/5/5/bbbbb a", &rule_set),
+            Err(Error::StackedOnOwnPiece(Player::new(0))),
+        );
+        let tall = RuleSet {
+            pallet_height: 4,
+            ..rule_set
+        };
+        assert!(parse("(abab)aaaa/5/5/5/5/bbbbb a", &tall).is_ok());
+    }
+
+    #[test]
+    fn parsed_game_is_playable() {
+        let game = Game::from_fen("3(aba)b/3(aba)(aba)/a4/5/5/5 a", RuleSet::default()).unwrap();
+        assert_eq!(game.legal_actions().len(), 17);
+        let game = game
+            .accept(&Action::new(
+                Position::new(Column::LeftEdge, Row::MiddleSecond),
+                Direction::Down,
+            ))
+            .unwrap();
+        assert_eq!(game.to_fen(), "3(aba)b/3(aba)(aba)/5/a4/5/5 a");
+    }
+}
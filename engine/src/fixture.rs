@@ -0,0 +1,12 @@
+use crate::{Action, Game};
+
+// An opening that stacks a piece of each player, shared by round-trip tests.
+pub(crate) const OPENING: [&str; 6] = ["a1-S", "a6-N", "a2-S", "a5-N", "b1-S", "a4-N"];
+
+impl Game {
+    pub(crate) fn play(&self, actions: &[&str]) -> Self {
+        actions.iter().fold(self.clone(), |game, action| {
+            game.accept(&action.parse::<Action>().unwrap()).unwrap()
+        })
+    }
+}
@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use crate::{board::Board, player::Player, Action};
+
+// Positions seen so far, kept as one stack per side to move since a position
+// only repeats with the same player to move. `apply` pushes and `revert` pops,
+// so searching in place stops allocating once the stacks have grown. `accept`
+// is not allocation-free: cloning the game copies both stacks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct History {
+    start: Arc<(Board, Player)>,
+    sides: [Vec<Record>; 2],
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Record {
+    index: usize,
+    action: Option<Action>,
+    hash: u64,
+    occurrence: usize,
+}
+
+impl History {
+    pub(crate) fn new(board: Board, player: Player) -> Self {
+        Self {
+            start: Arc::new((board, player)),
+            sides: [Vec::new(), Vec::new()],
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn start(&self) -> (&Board, Player) {
+        (&self.start.0, self.start.1)
+    }
+
+    pub(crate) fn push(&mut self, side: &Player, action: Option<Action>, hash: u64) {
+        let index = self.sides.iter().map(Vec::len).sum();
+        let records = &mut self.sides[side.id];
+        let occurrence = records
+            .iter()
+            .rev()
+            .find(|record| record.hash == hash)
+            .map_or(1, |record| record.occurrence + 1);
+        records.push(Record {
+            index,
+            action,
+            hash,
+            occurrence,
+        });
+    }
+
+    pub(crate) fn pop(&mut self, side: &Player) {
+        self.sides[side.id].pop();
+    }
+
+    pub(crate) fn occurrence(&self, side: &Player) -> usize {
+        self.sides[side.id]
+            .last()
+            .map_or(0, |record| record.occurrence)
+    }
+
+    #[cfg(any(feature = "serde", test))]
+    pub(crate) fn actions(&self) -> Vec<Action> {
+        let mut records = self.sides.iter().flatten().collect::<Vec<&Record>>();
+        records.sort_by_key(|record| record.index);
+        records
+            .into_iter()
+            .filter_map(|record| record.action)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod history_spec {
+    use super::History;
+    use crate::{player::Player, Action, Game};
+
+    fn history() -> History {
+        let game = Game::default();
+        History::new(game.board.clone(), Player::new(0))
+    }
+
+    #[test]
+    fn counts_occurrences() {
+        let (a, b) = (Player::new(0), Player::new(1));
+        let mut history = history();
+        assert_eq!(history.occurrence(&a), 0);
+        history.push(&a, None, 1);
+        history.push(&b, "a1-S".parse::<Action>().ok(), 1);
+        assert_eq!(history.occurrence(&b), 1);
+        history.push(&a, "a6-N".parse::<Action>().ok(), 1);
+        assert_eq!(history.occurrence(&a), 2);
+        history.push(&b, "a2-N".parse::<Action>().ok(), 1);
+        history.push(&a, "a5-S".parse::<Action>().ok(), 1);
+        assert_eq!(history.occurrence(&a), 3);
+        history.pop(&a);
+        assert_eq!(history.occurrence(&a), 2);
+        assert_eq!(
+            history
+                .actions()
+                .iter()
+                .map(|action| action.to_string())
+                .collect::<Vec<String>>(),
+            vec!["a1-S", "a6-N", "a2-N"]
+        );
+    }
+
+    #[test]
+    fn pop_keeps_capacity() {
+        let a = Player::new(0);
+        let mut history = history();
+        history.push(&a, None, 1);
+        history.push(&a, None, 2);
+        let capacity = history.sides[0].capacity();
+        for _ in 0..100 {
+            history.pop(&a);
+            history.push(&a, None, 2);
+        }
+        assert_eq!(history.sides[0].capacity(), capacity);
+        assert_eq!(history.occurrence(&a), 1);
+    }
+}
@@ -0,0 +1,39 @@
+use std::fmt;
+
+use crate::{fen::player_symbol, outcome::Outcome, player::Player, position::Position};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Legality {
+    Legal,
+    GameIsOver(Outcome),
+    OutOfField,
+    SourceIsEmpty,
+    NotYourStack(Player),
+    TargetIsFull(Position),
+    TargetAlreadyOwned(Position),
+}
+
+impl Legality {
+    pub fn is_legal(&self) -> bool {
+        self == &Self::Legal
+    }
+}
+
+impl fmt::Display for Legality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Legal => f.write_str("the move is legal"),
+            Self::GameIsOver(outcome) => write!(f, "the game is already over: {}", outcome),
+            Self::OutOfField => f.write_str("the move leaves the field"),
+            Self::SourceIsEmpty => f.write_str("there is no stack to move"),
+            Self::NotYourStack(owner) => {
+                write!(f, "the stack is topped by player {}", player_symbol(owner))
+            }
+            Self::TargetIsFull(position) => write!(f, "the stack on {} is full", position),
+            Self::TargetAlreadyOwned(position) => {
+                write!(f, "the stack on {} is already yours", position)
+            }
+        }
+    }
+}
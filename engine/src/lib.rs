@@ -1,21 +1,44 @@
 pub mod board;
-mod cell;
+pub mod builder;
+pub mod cell;
+mod diagram;
+mod fen;
+#[cfg(test)]
+mod fixture;
+mod history;
+pub mod legality;
+#[doc(hidden)]
+pub mod literal;
+pub mod outcome;
 pub mod player;
 pub mod position;
-mod result;
+#[cfg(feature = "serde")]
+mod record;
+pub mod result;
+pub mod rule;
+pub mod session;
+mod zobrist;
+
+use std::{fmt, str::FromStr};
 
-use board::{Board, CellMap, Direction};
+use board::{Board, DestinationState, Direction, Point};
+use cell::Cell;
+use history::History;
+use legality::Legality;
+use outcome::{DrawReason, Outcome, WinReason};
 use player::Player;
-use position::{Position, Row};
+use position::Position;
 use result::{Error, Result};
+use rule::{RuleSet, Stalemate};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct Phase {
     player: Player,
-    cell_map: CellMap,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Action {
     from: Position,
     direction: Direction,
@@ -25,43 +48,119 @@ impl Action {
     pub fn new(from: Position, direction: Direction) -> Self {
         Self { from, direction }
     }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.from, self.direction)
+    }
+}
+
+impl FromStr for Action {
+    type Err = Error;
 
-    fn destination(&self) -> Result<Position> {
-        self.direction.destination(&self.from)
+    fn from_str(s: &str) -> Result<Self> {
+        if let Some((from, to)) = s.split_once('>') {
+            let from = from.parse::<Position>()?;
+            let direction = Direction::between(&from, &to.parse::<Position>()?)
+                .map_err(|_| Error::InvalidNotation)?;
+            Ok(Self::new(from, direction))
+        } else if let Some((from, direction)) = s.split_once('-') {
+            Ok(Self::new(from.parse()?, direction.parse()?))
+        } else {
+            Err(Error::InvalidNotation)
+        }
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Undo {
+    from: (Position, Cell),
+    to: (Position, Cell),
+    player: Player,
+    outcome: Outcome,
+    stalemated: Option<Player>,
+}
+
+/// With the `serde` feature a game is written as
+/// `{"start": Board, "side": 0, "moves": [Action, ...], "outcome": Outcome}`:
+/// the position it started from, the player to move there and the moves played
+/// since. Reading replays the moves, so an illegal move, an unknown player or
+/// an outcome the moves do not lead to is an error; only a resignation or a
+/// timeout may end a game the moves leave in progress.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "record::GameRecord", try_from = "record::GameRecord")
+)]
 pub struct Game {
     player_a: Player,
     player_b: Player,
     pub board: Board,
     current_phase: Phase,
-    winner: Option<Player>,
+    outcome: Outcome,
+    stalemated: Option<Player>,
+    ply: usize,
+    history: History,
 }
 
 impl Game {
-    pub fn new() -> Self {
+    pub fn new(rule_set: RuleSet) -> Result<Self> {
+        rule_set.validate()?;
         let (player_a, player_b) = Self::spawn_players();
-        let board = Board::new(&player_a, &player_b);
-        let phase = Phase {
-            player: player_a,
-            cell_map: board.territory(&player_a),
-        };
-        Self {
-            player_a: player_a.clone(),
+        let board = Board::new(rule_set, &player_a, &player_b);
+        Ok(Self::from_board(board, player_a))
+    }
+
+    pub fn from_fen(fen: &str, rule_set: RuleSet) -> Result<Self> {
+        let (board, player) = fen::parse(fen, &rule_set)?;
+        Ok(Self::from_board(board, player))
+    }
+
+    pub fn to_fen(&self) -> String {
+        fen::format(self)
+    }
+
+    pub fn from_diagram(diagram: &str, rule_set: RuleSet) -> Result<Self> {
+        let (board, player) = diagram::parse(diagram, &rule_set)?;
+        Ok(Self::from_board(board, player))
+    }
+
+    fn from_board(board: Board, player: Player) -> Self {
+        let (player_a, player_b) = Self::spawn_players();
+        let phase = Phase { player };
+        let history = History::new(board.clone(), player);
+        let mut game = Self {
+            player_a,
             player_b,
             board,
             current_phase: phase,
-            winner: None,
-        }
+            outcome: Outcome::InProgress,
+            stalemated: None,
+            ply: 0,
+            history,
+        };
+        game.settle_stalemate();
+        let (side, hash) = (game.current_phase.player, game.hash());
+        game.history.push(&side, None, hash);
+        game
     }
 
-    fn goal_side(&self) -> Row {
-        if &self.current_phase.player == &self.player_a {
-            Row::Bottom
+    pub fn rule_set(&self) -> &RuleSet {
+        self.board.rule_set()
+    }
+
+    pub fn goal_side(&self) -> usize {
+        self.goal_side_of(&self.current_phase.player)
+    }
+
+    pub fn goal_side_of(&self, player: &Player) -> usize {
+        if *player == self.player_a {
+            self.rule_set().rows - 1
         } else {
-            Row::Top
+            0
         }
     }
 
@@ -69,8 +168,89 @@ impl Game {
         self.current_phase.player.clone()
     }
 
+    pub fn outcome(&self) -> Outcome {
+        self.outcome
+    }
+
+    pub fn winner(&self) -> Option<Player> {
+        self.outcome.winner()
+    }
+
+    pub fn is_draw(&self) -> bool {
+        self.outcome.is_draw()
+    }
+
     pub fn is_over(&self) -> bool {
-        self.winner.is_some()
+        self.outcome.is_over()
+    }
+
+    pub fn stalemated(&self) -> Option<Player> {
+        self.stalemated
+    }
+
+    pub fn ply(&self) -> usize {
+        self.ply
+    }
+
+    pub fn occurrence(&self) -> usize {
+        self.history.occurrence(&self.current_phase.player)
+    }
+
+    pub fn hash(&self) -> u64 {
+        self.board.hash() ^ zobrist::side(&self.current_phase.player)
+    }
+
+    pub fn legal_actions(&self) -> Vec<Action> {
+        if self.is_over() {
+            return Vec::new();
+        }
+        self.board
+            .iterate()
+            .filter(|position| {
+                self.board
+                    .is_occupied_by(position, &self.current_phase.player)
+            })
+            .flat_map(|position| {
+                self.board
+                    .moveable_directions(&position)
+                    .into_iter()
+                    .map(move |direction| Action::new(position, direction))
+            })
+            .collect()
+    }
+
+    fn has_legal_actions(&self) -> bool {
+        self.board
+            .territory(&self.current_phase.player)
+            .any(|(position, _)| {
+                self.board
+                    .moving_range_of(&position)
+                    .map(|moving_range| moving_range.is_moveable())
+                    .unwrap_or(false)
+            })
+    }
+
+    pub fn explain(&self, action: &Action) -> Legality {
+        if self.is_over() {
+            return Legality::GameIsOver(self.outcome);
+        }
+        let moving_range = match self.board.moving_range_of(&action.from) {
+            Ok(moving_range) => moving_range,
+            Err(_) => return Legality::OutOfField,
+        };
+        match moving_range.pivot.cell.owner() {
+            None => return Legality::SourceIsEmpty,
+            Some(owner) if owner != self.current_phase.player => {
+                return Legality::NotYourStack(owner)
+            }
+            _ => {}
+        }
+        match moving_range.state(&action.direction) {
+            DestinationState::Moveable(_) => Legality::Legal,
+            DestinationState::Fullfilled(point) => Legality::TargetIsFull(point.position),
+            DestinationState::AlreadyOwned(point) => Legality::TargetAlreadyOwned(point.position),
+            DestinationState::OutOfField => Legality::OutOfField,
+        }
     }
 
     fn spawn_players() -> (Player, Player) {
@@ -78,34 +258,122 @@ impl Game {
     }
 
     pub fn accept(&self, action: &Action) -> Result<Self> {
+        let mut next = self.clone();
+        next.apply(action)?;
+        Ok(next)
+    }
+
+    pub fn apply(&mut self, action: &Action) -> Result<Undo> {
+        self.verify_in_progress()?;
+        self.verify_owner(&action.from)?;
+        let from_cell = self.board.cell(&action.from)?;
+        let destination = self.refresh_board(&action.from, &action.direction)?;
+        let undo = Undo {
+            from: (action.from, from_cell),
+            to: (destination.position, destination.cell),
+            player: self.current_phase.player,
+            outcome: self.outcome,
+            stalemated: self.stalemated,
+        };
+        let is_isolated = self.board.is_isolated(&destination.position);
+        let is_reached_goal_side = self
+            .board
+            .is_reached_edge(&self.current_phase.player, self.goal_side());
+        if is_reached_goal_side && is_isolated {
+            self.outcome = Outcome::Win(self.current_phase.player, WinReason::Isolation);
+        }
+        self.current_phase = Phase {
+            player: self.opponent(),
+        };
+        self.stalemated = None;
+        self.ply += 1;
+        self.settle_stalemate();
+        let hash = self.hash();
+        self.history
+            .push(&self.current_phase.player, Some(*action), hash);
+        self.settle_draw();
+        Ok(undo)
+    }
+
+    pub fn revert(&mut self, undo: Undo) {
+        self.history.pop(&self.current_phase.player);
+        let (from, from_cell) = undo.from;
+        let (to, to_cell) = undo.to;
+        self.board.put(&from, from_cell);
+        self.board.put(&to, to_cell);
+        self.current_phase = Phase {
+            player: undo.player,
+        };
+        self.outcome = undo.outcome;
+        self.stalemated = undo.stalemated;
+        self.ply -= 1;
+    }
+
+    fn settle_draw(&mut self) {
         if self.is_over() {
-            return Err(Error::GameIsOver);
-        }
-        let board = self.refresh_board(&action.from, &action.direction)?;
-        let destination = action.destination()?;
-        let is_isolated = board.is_isolated(&destination);
-        let is_reached_goal_side =
-            board.is_reached_edge(&self.current_phase.player, &self.goal_side());
-        let winner = if is_reached_goal_side && is_isolated {
-            Some(self.current_phase.player)
-        } else {
-            None
+            return;
+        }
+        let occurrence = self.history.occurrence(&self.current_phase.player);
+        let reason = match (self.rule_set().repetition, self.rule_set().ply_limit) {
+            (Some(limit), _) if occurrence >= limit => DrawReason::Repetition,
+            (_, Some(limit)) if self.ply >= limit => DrawReason::PlyLimit,
+            _ => return,
         };
-        let next_player = self.next_player();
-        let next_phase = Phase {
-            player: next_player,
-            cell_map: board.territory(&next_player),
+        self.outcome = Outcome::Draw(reason);
+    }
+
+    fn settle_stalemate(&mut self) {
+        if self.is_over() || self.has_legal_actions() {
+            return;
+        }
+        self.stalemated = Some(self.current_phase.player);
+        match self.rule_set().stalemate {
+            Stalemate::Loss => {
+                self.outcome = Outcome::Win(self.opponent(), WinReason::Stalemate);
+            }
+            Stalemate::Draw => {
+                self.outcome = Outcome::Draw(DrawReason::Stalemate);
+            }
+            Stalemate::Pass => {
+                self.pass();
+                if !self.has_legal_actions() {
+                    self.pass();
+                    self.outcome = Outcome::Draw(DrawReason::Stalemate);
+                }
+            }
+        }
+    }
+
+    pub fn resign(&self, player: &Player) -> Result<Self> {
+        self.forfeit(player, WinReason::Resignation)
+    }
+
+    pub fn time_out(&self, player: &Player) -> Result<Self> {
+        self.forfeit(player, WinReason::Timeout)
+    }
+
+    fn forfeit(&self, loser: &Player, reason: WinReason) -> Result<Self> {
+        self.verify_in_progress()?;
+        let winner = if loser == &self.player_a {
+            self.player_b
+        } else if loser == &self.player_b {
+            self.player_a
+        } else {
+            return Err(Error::InvalidPlayer);
         };
         Ok(Self {
-            player_a: self.player_a.clone(),
-            player_b: self.player_b.clone(),
-            board,
-            current_phase: next_phase,
-            winner: winner,
+            outcome: Outcome::Win(winner, reason),
+            ..self.clone()
         })
     }
 
-    fn next_player(&self) -> Player {
+    fn pass(&mut self) {
+        self.current_phase = Phase {
+            player: self.opponent(),
+        };
+    }
+
+    pub fn opponent(&self) -> Player {
         if self.current_phase.player == self.player_a {
             self.player_b.clone()
         } else {
@@ -113,56 +381,164 @@ impl Game {
         }
     }
 
-    fn refresh_board(&self, position: &Position, direction: &Direction) -> Result<Board> {
-        let moving_range = self.board.moving_range_of(&position)?;
-        let destination = moving_range.indicate(&direction)?;
-        self.board.migrate(&position, &destination.position)
+    fn verify_in_progress(&self) -> Result<()> {
+        if self.is_over() {
+            Err(Error::GameIsOver(self.outcome))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn verify_owner(&self, position: &Position) -> Result<()> {
+        match self.board.cell(position)?.owner() {
+            Some(owner) if owner != self.current_phase.player => Err(Error::OwnedByOpponent(owner)),
+            _ => Ok(()),
+        }
+    }
+
+    fn refresh_board(&mut self, position: &Position, direction: &Direction) -> Result<Point> {
+        let moving_range = self.board.moving_range_of(position)?;
+        let destination = moving_range.indicate(direction)?;
+        self.board.migrate(position, &destination.position)?;
+        Ok(destination)
+    }
+}
+
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}\n{} to move",
+            self.board,
+            fen::player_symbol(&self.current_phase.player)
+        )
+    }
+}
+
+impl Default for Game {
+    fn default() -> Self {
+        Self::new(RuleSet::default()).unwrap()
     }
 }
 
 #[cfg(test)]
-mod game_spec {
-    use super::{Action, Game};
+mod action_spec {
+    use super::Action;
     use crate::{
         board::Direction,
+        position::{Column, Position, Row},
+        result::Error,
+        Game,
+    };
+
+    #[test]
+    fn notation() {
+        let action = Action::new(Position::new(Column::LeftEdge, Row::Top), Direction::Down);
+        assert_eq!(action.to_string(), "a1-S");
+        assert_eq!("a1-S".parse::<Action>(), Ok(action));
+        assert_eq!("a1>a2".parse::<Action>(), Ok(action));
+        assert_eq!(
+            "c3>b2".parse::<Action>(),
+            Ok(Action::new(
+                Position::new(Column::MiddleSecond, Row::MiddleSecond),
+                Direction::UpLeft,
+            )),
+        );
+        for invalid in ["", "a1", "a1-", "a1-X", "a1>a3", "a1>a1", "-S", "a1S"].iter() {
+            assert_eq!(invalid.parse::<Action>(), Err(Error::InvalidNotation));
+        }
+    }
+
+    #[test]
+    fn notation_round_trip() {
+        let game = Game::default();
+        for position in game.board.iterate() {
+            for direction in Direction::iterator() {
+                let action = Action::new(position, direction);
+                assert_eq!(action.to_string().parse::<Action>(), Ok(action));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod game_spec {
+    use super::{zobrist, Action, Game};
+    use crate::{
+        board::{CellMap, Direction},
         cell::Cell,
+        legality::Legality,
+        outcome::{DrawReason, Outcome, WinReason},
+        player::Player,
         position::{Column, Position, Row},
         result::Error,
+        rule::{RuleSet, Stalemate},
     };
 
     #[test]
     fn initial_state() {
-        let game = Game::new();
+        let game = Game::default();
         let initial_phase = &game.current_phase;
         assert_eq!(initial_phase.player, game.player_a);
         assert!(!game.is_over());
+        assert!(game
+            .board
+            .territory(&game.player_a)
+            .all(|(position, _)| position.y() == 0));
+    }
+
+    #[test]
+    fn custom_dimensions() {
+        for (columns, rows) in [(4, 5), (7, 8)].iter() {
+            let game = Game::new(RuleSet {
+                columns: *columns,
+                rows: *rows,
+                ..RuleSet::default()
+            })
+            .unwrap();
+            assert_eq!(game.board.cell_map().len(), columns * rows);
+            assert_eq!(game.board.iterate().count(), columns * rows);
+            assert_eq!(game.goal_side(), rows - 1);
+            assert_eq!(game.goal_side_of(&game.player_b), 0);
+            assert_eq!(game.opponent(), game.player_b);
+            let player_b_territory = game.board.territory(&game.player_b).collect::<CellMap>();
+            assert_eq!(player_b_territory.len(), *columns);
+            assert!(player_b_territory.keys().all(|p| p.y() == rows - 1));
+            assert_eq!(game.legal_actions().len(), 2 * 2 + 3 * (columns - 2));
+        }
+    }
+
+    #[test]
+    fn invalid_rule_set() {
         assert_eq!(
-            &initial_phase.cell_map,
-            &game.board.territory(&game.player_a)
+            Game::new(RuleSet {
+                rows: 1,
+                ..RuleSet::default()
+            }),
+            Err(Error::InvalidRuleSet),
         );
     }
 
     #[test]
     fn refresh_board() {
-        let game = Game::new();
+        let mut game = Game::default();
         let from_position = Position::new(Column::LeftEdge, Row::Top);
         let direction = Direction::Down;
-        if let Ok(board) = game.refresh_board(&from_position, &direction) {
-            assert!(board.cell_map.get(&from_position).unwrap().is_empty());
-            let to_position = from_position.below().unwrap();
+        if game.refresh_board(&from_position, &direction).is_ok() {
+            let board = &game.board;
+            assert!(board.cell(&from_position).unwrap().is_empty());
+            let to_position = from_position.below(game.rule_set()).unwrap();
             assert_eq!(
-                board.cell_map.get(&to_position),
-                Some(&Cell::new_occupied(game.player_a.clone()))
+                board.cell(&to_position),
+                Ok(Cell::new_occupied(game.player_a.clone()))
             );
         } else {
             panic!("fail");
         }
     }
 
-    #[test]
-    fn flip_turn() {
-        let mut game = Game::new();
-        let turns = [
+    fn isolation_win() -> [Action; 11] {
+        [
             Action::new(Position::new(Column::LeftEdge, Row::Top), Direction::Down),
             Action::new(Position::new(Column::LeftEdge, Row::Bottom), Direction::Up),
             Action::new(
@@ -201,31 +577,516 @@ mod game_spec {
                 Position::new(Column::MiddleFirst, Row::Top),
                 Direction::Down,
             ),
-        ];
+        ]
+    }
+
+    #[test]
+    fn flip_turn() {
+        let mut game = Game::default();
+        let turns = isolation_win();
         turns.iter().for_each(|action| {
             let result = game.accept(action);
             assert!(!game.is_over());
             game = result.unwrap();
         });
-        assert_eq!(game.winner, Some(game.player_a));
+        assert_eq!(
+            game.outcome(),
+            Outcome::Win(game.player_a, WinReason::Isolation)
+        );
         assert!(game.is_over());
         assert_eq!(
             game.accept(&Action::new(
                 Position::new(Column::MiddleFirst, Row::MiddleThird),
                 Direction::Up,
             )),
-            Err(Error::GameIsOver),
+            Err(Error::GameIsOver(game.outcome())),
+        );
+    }
+
+    #[test]
+    fn no_legal_actions_after_game_over() {
+        let game = isolation_win()
+            .iter()
+            .fold(Game::default(), |game, action| game.accept(action).unwrap());
+        assert!(game.is_over());
+        assert!(game.legal_actions().is_empty());
+    }
+
+    #[test]
+    fn cannot_move_opponents_cell() {
+        let game = Game::default();
+        assert_eq!(
+            game.accept(&Action::new(
+                Position::new(Column::LeftEdge, Row::Bottom),
+                Direction::Up,
+            )),
+            Err(Error::OwnedByOpponent(game.player_b)),
+        );
+    }
+
+    fn stacking_opening() -> [Action; 6] {
+        [
+            Action::new(Position::new(Column::LeftEdge, Row::Top), Direction::Down),
+            Action::new(Position::new(Column::LeftEdge, Row::Bottom), Direction::Up),
+            Action::new(
+                Position::new(Column::LeftEdge, Row::MiddleFirst),
+                Direction::Down,
+            ),
+            Action::new(
+                Position::new(Column::LeftEdge, Row::MiddleFourth),
+                Direction::Up,
+            ),
+            Action::new(
+                Position::new(Column::MiddleFirst, Row::Top),
+                Direction::Down,
+            ),
+            Action::new(
+                Position::new(Column::LeftEdge, Row::MiddleThird),
+                Direction::Up,
+            ),
+        ]
+    }
+
+    #[test]
+    fn cannot_move_stack_topped_by_opponent() {
+        let mut game = Game::default();
+        stacking_opening().iter().for_each(|action| {
+            game = game.accept(action).unwrap();
+        });
+        let robbed_position = Position::new(Column::LeftEdge, Row::MiddleSecond);
+        assert_eq!(
+            game.board.cell(&robbed_position).unwrap().owner(),
+            Some(game.player_b),
+        );
+        assert_eq!(
+            game.accept(&Action::new(robbed_position, Direction::Down)),
+            Err(Error::OwnedByOpponent(game.player_b)),
+        );
+    }
+
+    #[test]
+    fn pallet_height_limit() {
+        let onto_stack = Action::new(
+            Position::new(Column::MiddleFirst, Row::MiddleFirst),
+            Direction::DownLeft,
+        );
+        for (pallet_height, is_stackable) in [(2, false), (3, true)].iter() {
+            let mut game = Game::new(RuleSet {
+                pallet_height: *pallet_height,
+                ..RuleSet::default()
+            })
+            .unwrap();
+            stacking_opening().iter().for_each(|action| {
+                game = game.accept(action).unwrap();
+            });
+            assert_eq!(game.legal_actions().contains(&onto_stack), *is_stackable);
+            let accepted = game.accept(&onto_stack);
+            if *is_stackable {
+                assert!(accepted.is_ok());
+            } else {
+                assert_eq!(accepted, Err(Error::CellIsFullfilled));
+            }
+        }
+    }
+
+    #[test]
+    fn initial_legal_actions() {
+        let game = Game::default();
+        let expected = [
+            (
+                Column::LeftEdge,
+                vec![Direction::Down, Direction::DownRight],
+            ),
+            (
+                Column::MiddleFirst,
+                vec![Direction::Down, Direction::DownRight, Direction::DownLeft],
+            ),
+            (
+                Column::MiddleSecond,
+                vec![Direction::Down, Direction::DownRight, Direction::DownLeft],
+            ),
+            (
+                Column::MiddleThird,
+                vec![Direction::Down, Direction::DownRight, Direction::DownLeft],
+            ),
+            (
+                Column::RightEdge,
+                vec![Direction::Down, Direction::DownLeft],
+            ),
+        ]
+        .iter()
+        .flat_map(|(column, directions)| {
+            directions
+                .iter()
+                .map(move |direction| Action::new(Position::new(*column, Row::Top), *direction))
+        })
+        .collect::<Vec<Action>>();
+        assert_eq!(game.legal_actions(), expected);
+    }
+
+    #[test]
+    fn legal_actions_agree_with_accept() {
+        let mut game = Game::default();
+        for action in stacking_opening().iter() {
+            let legal_actions = game.legal_actions();
+            for position in game.board.iterate() {
+                for direction in Direction::iterator() {
+                    let candidate = Action::new(position, direction);
+                    assert_eq!(
+                        game.accept(&candidate).is_ok(),
+                        legal_actions.contains(&candidate),
+                    );
+                }
+            }
+            game = game.accept(action).unwrap();
+        }
+    }
+
+    fn stalemate_game(stalemate: Stalemate) -> Game {
+        Game::from_fen(
+            "3(aba)b/3(aba)(aba)/a4/5/5/5 a",
+            RuleSet {
+                stalemate,
+                ..RuleSet::default()
+            },
+        )
+        .unwrap()
+    }
+
+    fn stalemate_action() -> Action {
+        Action::new(
+            Position::new(Column::LeftEdge, Row::MiddleSecond),
+            Direction::Down,
+        )
+    }
+
+    #[test]
+    fn stalemate_as_pass() {
+        let game = stalemate_game(Stalemate::Pass)
+            .accept(&stalemate_action())
+            .unwrap();
+        assert!(!game.is_over());
+        assert_eq!(game.stalemated(), Some(game.player_b));
+        assert_eq!(game.current_player(), game.player_a);
+        assert!(!game.legal_actions().is_empty());
+    }
+
+    #[test]
+    fn stalemate_as_loss() {
+        let game = stalemate_game(Stalemate::Loss)
+            .accept(&stalemate_action())
+            .unwrap();
+        assert!(game.is_over());
+        assert!(!game.is_draw());
+        assert_eq!(game.stalemated(), Some(game.player_b));
+        assert_eq!(
+            game.outcome(),
+            Outcome::Win(game.player_a, WinReason::Stalemate)
+        );
+    }
+
+    #[test]
+    fn stalemate_as_draw() {
+        let game = stalemate_game(Stalemate::Draw)
+            .accept(&stalemate_action())
+            .unwrap();
+        assert!(game.is_over());
+        assert!(game.is_draw());
+        assert_eq!(game.stalemated(), Some(game.player_b));
+        assert_eq!(game.outcome(), Outcome::Draw(DrawReason::Stalemate));
+        assert_eq!(
+            game.accept(&stalemate_action()),
+            Err(Error::GameIsOver(game.outcome()))
+        );
+    }
+
+    fn shuttle() -> [Action; 4] {
+        [
+            Action::new(Position::new(Column::LeftEdge, Row::Top), Direction::Down),
+            Action::new(Position::new(Column::LeftEdge, Row::Bottom), Direction::Up),
+            Action::new(
+                Position::new(Column::LeftEdge, Row::MiddleFirst),
+                Direction::Up,
+            ),
+            Action::new(
+                Position::new(Column::LeftEdge, Row::MiddleFourth),
+                Direction::Down,
+            ),
+        ]
+    }
+
+    #[test]
+    fn draw_by_repetition() {
+        let mut game = Game::default();
+        assert_eq!(game.occurrence(), 1);
+        shuttle().iter().for_each(|action| {
+            game = game.accept(action).unwrap();
+        });
+        assert!(!game.is_over());
+        assert_eq!(game.board, Game::default().board);
+        assert_eq!(game.occurrence(), 2);
+        shuttle().iter().take(3).for_each(|action| {
+            game = game.accept(action).unwrap();
+        });
+        assert!(!game.is_over());
+        game = game.accept(&shuttle()[3]).unwrap();
+        assert!(game.is_over());
+        assert!(game.is_draw());
+        assert_eq!(game.winner(), None);
+        assert_eq!(game.outcome(), Outcome::Draw(DrawReason::Repetition));
+        assert_eq!(game.ply(), 8);
+    }
+
+    #[test]
+    fn repetition_can_be_disabled() {
+        let mut game = Game::new(RuleSet {
+            repetition: None,
+            ..RuleSet::default()
+        })
+        .unwrap();
+        for _ in 0..3 {
+            shuttle().iter().for_each(|action| {
+                game = game.accept(action).unwrap();
+            });
+        }
+        assert!(!game.is_over());
+        assert_eq!(game.ply(), 12);
+    }
+
+    #[test]
+    fn draw_by_ply_limit() {
+        let mut game = Game::new(RuleSet {
+            ply_limit: Some(3),
+            ..RuleSet::default()
+        })
+        .unwrap();
+        shuttle().iter().take(2).for_each(|action| {
+            game = game.accept(action).unwrap();
+        });
+        assert!(!game.is_over());
+        game = game.accept(&shuttle()[2]).unwrap();
+        assert!(game.is_draw());
+        assert_eq!(game.outcome(), Outcome::Draw(DrawReason::PlyLimit));
+        assert_eq!(
+            game.accept(&shuttle()[3]),
+            Err(Error::GameIsOver(game.outcome()))
+        );
+    }
+
+    #[test]
+    fn transpositions_hash_equally() {
+        let game = Game::default().play(&["a1-S", "a6-N", "b1-S", "b6-N"]);
+        let transposed = Game::default().play(&["b1-S", "b6-N", "a1-S", "a6-N"]);
+        assert_eq!(game.board, transposed.board);
+        assert_eq!(game.hash(), transposed.hash());
+        assert_ne!(game.hash(), Game::default().hash());
+        let rebuilt = Game::from_fen(&game.to_fen(), *game.rule_set()).unwrap();
+        assert_eq!(rebuilt.hash(), game.hash());
+    }
+
+    #[test]
+    fn hash_depends_on_side_to_move() {
+        let game = Game::from_fen("aaaaa/5/5/5/5/bbbbb a", RuleSet::default()).unwrap();
+        let other_side = Game::from_fen("aaaaa/5/5/5/5/bbbbb b", RuleSet::default()).unwrap();
+        assert_eq!(game.board, other_side.board);
+        assert_ne!(game.hash(), other_side.hash());
+    }
+
+    #[test]
+    fn apply_and_revert() {
+        let mut game = Game::default();
+        let initial = game.clone();
+        let action = "a1-S".parse::<Action>().unwrap();
+        let undo = game.apply(&action).unwrap();
+        assert_eq!(game, initial.accept(&action).unwrap());
+        game.revert(undo);
+        assert_eq!(game, initial);
+        assert_eq!(
+            game.apply(&"a6-N".parse::<Action>().unwrap()),
+            Err(Error::OwnedByOpponent(game.player_b))
+        );
+        assert_eq!(game, initial);
+    }
+
+    #[test]
+    fn revert_restores_random_games() {
+        let mut seed = 0x6e63_3200_0000_0014;
+        let rule_sets = [
+            RuleSet::default(),
+            RuleSet {
+                stalemate: Stalemate::Loss,
+                ..RuleSet::default()
+            },
+            RuleSet {
+                columns: 4,
+                rows: 4,
+                pallet_height: 2,
+                stalemate: Stalemate::Draw,
+                repetition: None,
+                ply_limit: Some(60),
+            },
+        ];
+        for rule_set in rule_sets.iter() {
+            for _ in 0..30 {
+                let mut game = Game::new(*rule_set).unwrap();
+                let mut history = Vec::new();
+                while !game.is_over() && history.len() < 200 {
+                    let actions = game.legal_actions();
+                    seed = zobrist::splitmix64(seed);
+                    let action = actions[seed as usize % actions.len()];
+                    let before = game.clone();
+                    let undo = game.apply(&action).unwrap();
+                    history.push((before, undo));
+                }
+                while let Some((before, undo)) = history.pop() {
+                    game.revert(undo);
+                    assert_eq!(game, before);
+                    assert_eq!(game.hash(), before.hash());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn win_by_isolation_on_goal_row() {
+        let game = crate::game!(a;
+            [. . . . .]
+            [. . . . .]
+            [. . . . .]
+            [. . . . .]
+            [a . . . .]
+            [. . b b b]
+        );
+        let won = game.accept(&"a5-S".parse::<Action>().unwrap()).unwrap();
+        assert_eq!(
+            won.outcome(),
+            Outcome::Win(game.player_a, WinReason::Isolation)
+        );
+        let touching = game.accept(&"a5-SE".parse::<Action>().unwrap()).unwrap();
+        assert!(!touching.is_over());
+
+        let game = crate::game!(b;
+            [. . . a a]
+            [b . . . .]
+            [. . . . .]
+            [. . . . .]
+            [. . . . .]
+            [. . . . .]
+        );
+        let won = game.accept(&"a2-N".parse::<Action>().unwrap()).unwrap();
+        assert_eq!(
+            won.outcome(),
+            Outcome::Win(game.player_b, WinReason::Isolation)
+        );
+    }
+
+    #[test]
+    fn win_needs_isolated_destination() {
+        let game = crate::game!(a;
+            [. . . . .]
+            [. . . . .]
+            [. . a . .]
+            [. . . . .]
+            [. . . . b]
+            [a . . . b]
+        );
+        let won = game.accept(&"c3-S".parse::<Action>().unwrap()).unwrap();
+        assert_eq!(
+            won.outcome(),
+            Outcome::Win(game.player_a, WinReason::Isolation)
+        );
+        let touching = game.accept(&"c3-SE".parse::<Action>().unwrap()).unwrap();
+        assert!(!touching.is_over());
+    }
+
+    #[test]
+    fn explain() {
+        let game = crate::game!(a;
+            [ba a aba]
+            [b . .]
+            [. . b]
+        );
+        let explain = |action: &str| game.explain(&action.parse::<Action>().unwrap());
+        assert_eq!(explain("a1-S"), Legality::Legal);
+        assert_eq!(explain("a1-N"), Legality::OutOfField);
+        assert_eq!(explain("d1-S"), Legality::OutOfField);
+        assert_eq!(explain("b2-S"), Legality::SourceIsEmpty);
+        assert_eq!(explain("a2-N"), Legality::NotYourStack(game.player_b));
+        assert_eq!(explain("b1-E"), Legality::TargetIsFull(Position::at(2, 0)));
+        assert_eq!(
+            explain("a1-E"),
+            Legality::TargetAlreadyOwned(Position::at(1, 0))
+        );
+        let resigned = game.resign(&game.player_a).unwrap();
+        assert_eq!(
+            resigned.explain(&"a1-S".parse::<Action>().unwrap()),
+            Legality::GameIsOver(resigned.outcome())
+        );
+        assert_eq!(
+            resigned
+                .explain(&"a1-S".parse::<Action>().unwrap())
+                .to_string(),
+            "the game is already over: player b won by resignation"
+        );
+    }
+
+    #[test]
+    fn explain_agrees_with_apply() {
+        let mut seed = 0x6e63_3200_0000_0020;
+        let mut game = Game::default();
+        while !game.is_over() {
+            let legal_actions = game.legal_actions();
+            for position in game.board.iterate() {
+                for direction in Direction::iterator() {
+                    let action = Action::new(position, direction);
+                    let legality = game.explain(&action);
+                    assert_eq!(legality.is_legal(), game.accept(&action).is_ok());
+                    assert_eq!(legality.is_legal(), legal_actions.contains(&action));
+                }
+            }
+            seed = zobrist::splitmix64(seed);
+            game.apply(&legal_actions[seed as usize % legal_actions.len()])
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn resign() {
+        let game = Game::default();
+        let resigned = game.resign(&game.player_a).unwrap();
+        assert_eq!(
+            resigned.outcome(),
+            Outcome::Win(game.player_b, WinReason::Resignation)
+        );
+        assert_eq!(
+            resigned.resign(&game.player_b),
+            Err(Error::GameIsOver(resigned.outcome()))
+        );
+        assert_eq!(game.resign(&Player::new(2)), Err(Error::InvalidPlayer));
+    }
+
+    #[test]
+    fn time_out() {
+        let game = Game::default()
+            .accept(&shuttle()[0])
+            .unwrap()
+            .time_out(&Player::new(1))
+            .unwrap();
+        assert!(game.is_over());
+        assert_eq!(
+            game.outcome(),
+            Outcome::Win(game.player_a, WinReason::Timeout)
         );
     }
 
     #[test]
     fn error_by_out_of_world() {
-        let game = Game::new();
+        let mut game = Game::default();
         let from_position = Position::new(Column::LeftEdge, Row::Top);
         let direction = Direction::Up;
         assert_eq!(
             game.refresh_board(&from_position, &direction),
-            Err(Error::IllegalDestination),
+            Err(Error::IllegalDestination(from_position, direction)),
         );
     }
 }
@@ -0,0 +1,124 @@
+use crate::{board::Board, diagram, fen, player::Player, result::Result, rule::RuleSet, Game};
+
+#[macro_export]
+macro_rules! board {
+    ($([$($cell:tt)*])*) => {
+        $crate::literal::board(None, &[$(&[$(stringify!($cell)),*][..]),*]).unwrap()
+    };
+    ($rule_set:expr; $([$($cell:tt)*])*) => {
+        $crate::literal::board(Some($rule_set), &[$(&[$(stringify!($cell)),*][..]),*]).unwrap()
+    };
+}
+
+#[macro_export]
+macro_rules! game {
+    ($side:ident; $([$($cell:tt)*])*) => {
+        $crate::literal::game(
+            stringify!($side),
+            None,
+            &[$(&[$(stringify!($cell)),*][..]),*],
+        )
+        .unwrap()
+    };
+    ($side:ident, $rule_set:expr; $([$($cell:tt)*])*) => {
+        $crate::literal::game(
+            stringify!($side),
+            Some($rule_set),
+            &[$(&[$(stringify!($cell)),*][..]),*],
+        )
+        .unwrap()
+    };
+}
+
+pub fn board(rule_set: Option<RuleSet>, rows: &[&[&str]]) -> Result<Board> {
+    parse("a", rule_set, rows).map(|(board, _)| board)
+}
+
+pub fn game(side: &str, rule_set: Option<RuleSet>, rows: &[&[&str]]) -> Result<Game> {
+    let (board, player) = parse(side, rule_set, rows)?;
+    Ok(Game::from_board(board, player))
+}
+
+fn parse(side: &str, rule_set: Option<RuleSet>, rows: &[&[&str]]) -> Result<(Board, Player)> {
+    let rule_set = match rule_set {
+        Some(rule_set) => rule_set,
+        None => RuleSet {
+            columns: rows.first().map(|row| width(row)).unwrap_or(0),
+            rows: rows.len(),
+            ..RuleSet::default()
+        },
+    };
+    let rows = rows
+        .iter()
+        .map(|row| diagram::fen_row(row.iter().copied()))
+        .collect::<Result<Vec<String>>>()?;
+    fen::parse(&format!("{} {}", rows.join("/"), side), &rule_set)
+}
+
+fn width(row: &[&str]) -> usize {
+    row.iter()
+        .map(|token| {
+            if token.chars().all(|c| c == '.') {
+                token.len()
+            } else {
+                1
+            }
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod literal_spec {
+    use crate::{player::Player, position::Position, rule::RuleSet};
+
+    #[test]
+    fn board_literal() {
+        let board = crate::board!([a..][ab.b]);
+        assert_eq!(board.rule_set().columns, 3);
+        assert_eq!(board.rule_set().rows, 2);
+        assert_eq!(board.to_string(), "  a   b   c\n1 a   .   .\n2 ab  .   b");
+    }
+
+    #[test]
+    fn literal_with_rule_set() {
+        let rule_set = RuleSet {
+            columns: 2,
+            rows: 2,
+            pallet_height: 4,
+            ..RuleSet::default()
+        };
+        let board = crate::board!(rule_set;
+            [abab a]
+            [. b]
+        );
+        assert_eq!(board.cell(&Position::at(0, 0)).unwrap().height(), 4);
+        let game = crate::game!(b, rule_set;
+            [abab a]
+            [. b]
+        );
+        assert_eq!(game.board, board);
+        assert_eq!(game.current_player(), Player::new(1));
+        assert_eq!(
+            crate::game!(a; [a .] [. b]).current_player(),
+            Player::new(0)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "InvalidBoardSize")]
+    fn ragged_literal() {
+        crate::board!(
+            [a b]
+            [a]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "ReachedPalletHeightLimit")]
+    fn overstacked_literal() {
+        crate::board!(
+            [abab b]
+            [a b]
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use std::fmt;
+
+use crate::{fen::player_symbol, player::Player};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WinReason {
+    Isolation,
+    Stalemate,
+    Resignation,
+    Timeout,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DrawReason {
+    Stalemate,
+    Repetition,
+    PlyLimit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Outcome {
+    InProgress,
+    Win(Player, WinReason),
+    Draw(DrawReason),
+}
+
+impl Outcome {
+    pub fn is_over(&self) -> bool {
+        self != &Self::InProgress
+    }
+
+    pub fn is_draw(&self) -> bool {
+        matches!(self, Self::Draw(_))
+    }
+
+    pub fn winner(&self) -> Option<Player> {
+        match self {
+            Self::Win(player, _) => Some(*player),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for WinReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Isolation => "isolation",
+            Self::Stalemate => "stalemate",
+            Self::Resignation => "resignation",
+            Self::Timeout => "timeout",
+        })
+    }
+}
+
+impl fmt::Display for DrawReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Stalemate => "stalemate",
+            Self::Repetition => "repetition",
+            Self::PlyLimit => "ply limit",
+        })
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InProgress => f.write_str("in progress"),
+            Self::Win(player, reason) => {
+                write!(f, "player {} won by {}", player_symbol(player), reason)
+            }
+            Self::Draw(reason) => write!(f, "draw by {}", reason),
+        }
+    }
+}
+
+#[cfg(test)]
+mod outcome_spec {
+    use super::{DrawReason, Outcome, WinReason};
+    use crate::player::Player;
+
+    #[test]
+    fn in_progress() {
+        let outcome = Outcome::InProgress;
+        assert!(!outcome.is_over());
+        assert!(!outcome.is_draw());
+        assert_eq!(outcome.winner(), None);
+    }
+
+    #[test]
+    fn win() {
+        let player = Player::new(0);
+        let outcome = Outcome::Win(player, WinReason::Isolation);
+        assert!(outcome.is_over());
+        assert!(!outcome.is_draw());
+        assert_eq!(outcome.winner(), Some(player));
+    }
+
+    #[test]
+    fn draw() {
+        let outcome = Outcome::Draw(DrawReason::Repetition);
+        assert!(outcome.is_over());
+        assert!(outcome.is_draw());
+        assert_eq!(outcome.winner(), None);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Outcome::InProgress.to_string(), "in progress");
+        assert_eq!(
+            Outcome::Win(Player::new(1), WinReason::Resignation).to_string(),
+            "player b won by resignation"
+        );
+        assert_eq!(
+            Outcome::Draw(DrawReason::PlyLimit).to_string(),
+            "draw by ply limit"
+        );
+    }
+}
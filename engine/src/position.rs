@@ -1,6 +1,12 @@
-use crate::result::{Error, Result};
+use std::{fmt, str::FromStr};
+
+use crate::{
+    result::{Error, Result},
+    rule::{RuleSet, MAX_COLUMNS},
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Row {
     Top,
     MiddleFirst,
@@ -11,43 +17,6 @@ pub enum Row {
 }
 
 impl Row {
-    fn is_top(&self) -> bool {
-        self == &Self::Top
-    }
-
-    fn is_bottom(&self) -> bool {
-        self == &Self::Bottom
-    }
-
-    pub(crate) fn is_middle(&self) -> bool {
-        match self {
-            Self::Top | Self::Bottom => false,
-            _ => true,
-        }
-    }
-
-    fn above(&self) -> Result<Self> {
-        match self {
-            Self::Top => Err(Error::ReachedTop),
-            Self::MiddleFirst => Ok(Self::Top),
-            Self::MiddleSecond => Ok(Self::MiddleFirst),
-            Self::MiddleThird => Ok(Self::MiddleSecond),
-            Self::MiddleFourth => Ok(Self::MiddleThird),
-            Self::Bottom => Ok(Self::MiddleFourth),
-        }
-    }
-
-    fn below(&self) -> Result<Self> {
-        match self {
-            Self::Top => Ok(Self::MiddleFirst),
-            Self::MiddleFirst => Ok(Self::MiddleSecond),
-            Self::MiddleSecond => Ok(Self::MiddleThird),
-            Self::MiddleThird => Ok(Self::MiddleFourth),
-            Self::MiddleFourth => Ok(Self::Bottom),
-            Self::Bottom => Err(Error::ReachedBottom),
-        }
-    }
-
     pub fn iterator() -> impl Iterator<Item = Self> {
         [
             Self::Top,
@@ -56,11 +25,20 @@ impl Row {
             Self::MiddleThird,
             Self::MiddleFourth,
             Self::Bottom,
-        ].iter().copied()
+        ]
+        .iter()
+        .copied()
+    }
+}
+
+impl From<Row> for usize {
+    fn from(row: Row) -> Self {
+        row as usize
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Column {
     LeftEdge,
     MiddleFirst,
@@ -68,42 +46,8 @@ pub enum Column {
     MiddleThird,
     RightEdge,
 }
-impl Column {
-    fn is_left_edge(&self) -> bool {
-        self == &Self::LeftEdge
-    }
-
-    fn is_right_edge(&self) -> bool {
-        self == &Self::RightEdge
-    }
-
-    fn is_middle(&self) -> bool {
-        match *self {
-            Self::LeftEdge | Self::RightEdge => false,
-            _ => true,
-        }
-    }
-
-    fn righthand(&self) -> Result<Self> {
-        match *self {
-            Self::LeftEdge => Ok(Self::MiddleFirst),
-            Self::MiddleFirst => Ok(Self::MiddleSecond),
-            Self::MiddleSecond => Ok(Self::MiddleThird),
-            Self::MiddleThird => Ok(Self::RightEdge),
-            Self::RightEdge => Err(Error::ReachedRightEdge),
-        }
-    }
-
-    fn lefthand(&self) -> Result<Self> {
-        match *self {
-            Self::LeftEdge => Err(Error::ReachedLeftEdge),
-            Self::MiddleFirst => Ok(Self::LeftEdge),
-            Self::MiddleSecond => Ok(Self::MiddleFirst),
-            Self::MiddleThird => Ok(Self::MiddleSecond),
-            Self::RightEdge => Ok(Self::MiddleThird),
-        }
-    }
 
+impl Column {
     pub fn iterator() -> impl Iterator<Item = Self> {
         [
             Self::LeftEdge,
@@ -117,101 +61,184 @@ impl Column {
     }
 }
 
+impl From<Column> for usize {
+    fn from(column: Column) -> Self {
+        column as usize
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Hash, Clone, PartialOrd, Ord, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Position {
-    x: Column,
-    y: Row,
+    x: usize,
+    y: usize,
 }
 
 impl Position {
     pub fn new(x: Column, y: Row) -> Self {
+        Self::at(x.into(), y.into())
+    }
+
+    pub fn at(x: usize, y: usize) -> Self {
         Self { x, y }
     }
 
-    pub(self) fn vertical(&self, y: Result<Row>) -> Result<Self> {
-        y.map(|y| Self {
-            x: self.x.clone(),
-            y,
-        })
+    pub fn x(&self) -> usize {
+        self.x
     }
 
-    pub(crate) fn above(&self) -> Result<Self> {
-        self.vertical(self.y.above())
+    pub fn y(&self) -> usize {
+        self.y
     }
 
-    pub(crate) fn below(&self) -> Result<Self> {
-        self.vertical(self.y.below())
+    pub fn is_inside(&self, rule_set: &RuleSet) -> bool {
+        self.x < rule_set.columns && self.y < rule_set.rows
     }
 
-    pub(self) fn horizon(&self, x: Result<Column>) -> Result<Self> {
-        x.map(|x| Self {
-            x,
-            y: self.y.clone(),
-        })
+    pub(crate) fn above(&self) -> Result<Self> {
+        if self.y == 0 {
+            Err(Error::ReachedTop)
+        } else {
+            Ok(Self::at(self.x, self.y - 1))
+        }
+    }
+
+    pub(crate) fn below(&self, rule_set: &RuleSet) -> Result<Self> {
+        if self.y + 1 >= rule_set.rows {
+            Err(Error::ReachedBottom)
+        } else {
+            Ok(Self::at(self.x, self.y + 1))
+        }
     }
 
-    pub(crate) fn righthand(&self) -> Result<Self> {
-        self.horizon(self.x.righthand())
+    pub(crate) fn righthand(&self, rule_set: &RuleSet) -> Result<Self> {
+        if self.x + 1 >= rule_set.columns {
+            Err(Error::ReachedRightEdge)
+        } else {
+            Ok(Self::at(self.x + 1, self.y))
+        }
     }
 
     pub(crate) fn lefthand(&self) -> Result<Self> {
-        self.horizon(self.x.lefthand())
+        if self.x == 0 {
+            Err(Error::ReachedLeftEdge)
+        } else {
+            Ok(Self::at(self.x - 1, self.y))
+        }
     }
 
-    pub(crate) fn above_righthand(&self) -> Result<Self> {
-        self.above().and_then(|p| p.righthand())
+    pub(crate) fn above_righthand(&self, rule_set: &RuleSet) -> Result<Self> {
+        self.above().and_then(|p| p.righthand(rule_set))
     }
 
     pub(crate) fn above_lefthand(&self) -> Result<Self> {
         self.above().and_then(|p| p.lefthand())
     }
 
-    pub(crate) fn below_righthand(&self) -> Result<Self> {
-        self.below().and_then(|p| p.righthand())
+    pub(crate) fn below_righthand(&self, rule_set: &RuleSet) -> Result<Self> {
+        self.below(rule_set).and_then(|p| p.righthand(rule_set))
+    }
+
+    pub(crate) fn below_lefthand(&self, rule_set: &RuleSet) -> Result<Self> {
+        self.below(rule_set).and_then(|p| p.lefthand())
+    }
+}
+
+#[cfg(test)]
+impl Position {
+    fn is_left_edge(&self) -> bool {
+        self.x == 0
+    }
+
+    fn is_right_edge(&self, rule_set: &RuleSet) -> bool {
+        self.x + 1 == rule_set.columns
+    }
+
+    fn is_middle_column(&self, rule_set: &RuleSet) -> bool {
+        !self.is_left_edge() && !self.is_right_edge(rule_set)
+    }
+
+    fn is_middle_row(&self, rule_set: &RuleSet) -> bool {
+        !self.is_top() && !self.is_bottom(rule_set)
+    }
+
+    fn is_top(&self) -> bool {
+        self.y == 0
     }
 
-    pub(crate) fn below_lefthand(&self) -> Result<Self> {
-        self.below().and_then(|p| p.lefthand())
+    fn is_bottom(&self, rule_set: &RuleSet) -> bool {
+        self.y + 1 == rule_set.rows
     }
 
-    pub(crate) fn is_top(&self) -> bool {
-        self.y.is_top()
+    fn is_left_edge_top(&self) -> bool {
+        self.is_left_edge() && self.is_top()
     }
 
-    pub(crate) fn is_bottom(&self) -> bool {
-        self.y.is_bottom()
+    fn is_left_edge_bottom(&self, rule_set: &RuleSet) -> bool {
+        self.is_left_edge() && self.is_bottom(rule_set)
     }
 
-    pub(crate) fn is_left_edge_top(&self) -> bool {
-        self.x.is_left_edge() && self.is_top()
+    fn is_right_edge_top(&self, rule_set: &RuleSet) -> bool {
+        self.is_right_edge(rule_set) && self.is_top()
     }
 
-    pub(crate) fn is_left_edge_bottom(&self) -> bool {
-        self.x.is_left_edge() && self.is_bottom()
+    fn is_right_edge_bottom(&self, rule_set: &RuleSet) -> bool {
+        self.is_right_edge(rule_set) && self.is_bottom(rule_set)
     }
 
-    pub(crate) fn is_right_edge_top(&self) -> bool {
-        self.x.is_right_edge() && self.is_top()
+    fn is_right_edge_middle_row(&self, rule_set: &RuleSet) -> bool {
+        self.is_right_edge(rule_set) && self.is_middle_row(rule_set)
     }
 
-    pub(crate) fn is_right_edge_bottom(&self) -> bool {
-        self.x.is_right_edge() && self.is_bottom()
+    fn is_left_edge_middle_row(&self, rule_set: &RuleSet) -> bool {
+        self.is_left_edge() && self.is_middle_row(rule_set)
     }
 
-    pub(crate) fn is_right_edge_middle_row(&self) -> bool {
-        self.x.is_right_edge() && self.y.is_middle()
+    fn is_middle_column_top(&self, rule_set: &RuleSet) -> bool {
+        self.is_middle_column(rule_set) && self.is_top()
     }
 
-    pub(crate) fn is_left_edge_middle_row(&self) -> bool {
-        self.x.is_left_edge() && self.y.is_middle()
+    fn is_middle_column_bottom(&self, rule_set: &RuleSet) -> bool {
+        self.is_middle_column(rule_set) && self.is_bottom(rule_set)
     }
+}
 
-    pub(crate) fn is_middle_column_top(&self) -> bool {
-        self.x.is_middle() && self.is_top()
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", ColumnSymbol(self.x), self.y + 1)
     }
+}
+
+// `Position::at` takes any column, so columns past 'z' are written as their
+// index in brackets rather than as an overflowed letter.
+pub(crate) struct ColumnSymbol(pub(crate) usize);
 
-    pub(crate) fn is_middle_column_bottom(&self) -> bool {
-        self.x.is_middle() && self.is_bottom()
+impl fmt::Display for ColumnSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0 < MAX_COLUMNS {
+            f.pad(((b'a' + self.0 as u8) as char).encode_utf8(&mut [0; 4]))
+        } else {
+            f.pad(&format!("[{}]", self.0))
+        }
+    }
+}
+
+impl FromStr for Position {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut chars = s.chars();
+        let x = match chars.next() {
+            Some(column @ 'a'..='z') => column as usize - 'a' as usize,
+            _ => return Err(Error::InvalidNotation),
+        };
+        let y = match chars.as_str().parse::<usize>() {
+            Ok(row) if row > 0 && chars.as_str().starts_with(|c: char| c.is_ascii_digit()) => {
+                row - 1
+            }
+            _ => return Err(Error::InvalidNotation),
+        };
+        Ok(Self::at(x, y))
     }
 }
 
@@ -228,7 +255,7 @@ fn left_edge_bottom_position() {
     let left_edge = Column::LeftEdge;
     let bottom_edge = Row::Bottom;
     let position = Position::new(left_edge, bottom_edge);
-    assert!(position.is_left_edge_bottom());
+    assert!(position.is_left_edge_bottom(&RuleSet::default()));
 }
 
 #[test]
@@ -236,7 +263,7 @@ fn right_edge_top_position() {
     let right_edge = Column::RightEdge;
     let top_edge = Row::Top;
     let position = Position::new(right_edge, top_edge);
-    assert!(position.is_right_edge_top());
+    assert!(position.is_right_edge_top(&RuleSet::default()));
 }
 
 #[test]
@@ -244,7 +271,7 @@ fn right_edge_bottom_position() {
     let right_edge = Column::RightEdge;
     let bottom_edge = Row::Bottom;
     let position = Position::new(right_edge, bottom_edge);
-    assert!(position.is_right_edge_bottom());
+    assert!(position.is_right_edge_bottom(&RuleSet::default()));
 }
 
 #[test]
@@ -259,7 +286,7 @@ fn left_edge_middle_row() {
     .iter()
     {
         let position = Position::new(left_edge.clone(), row.to_owned());
-        assert!(position.is_left_edge_middle_row());
+        assert!(position.is_left_edge_middle_row(&RuleSet::default()));
     }
 }
 
@@ -275,7 +302,7 @@ fn right_edge_middle_row() {
     .iter()
     {
         let position = Position::new(right_edge.clone(), row.to_owned());
-        assert!(position.is_right_edge_middle_row());
+        assert!(position.is_right_edge_middle_row(&RuleSet::default()));
     }
 }
 
@@ -290,7 +317,7 @@ fn middle_column_top() {
     .iter()
     {
         let position = Position::new(column.to_owned(), top.clone());
-        assert!(position.is_middle_column_top());
+        assert!(position.is_middle_column_top(&RuleSet::default()));
     }
 }
 
@@ -305,7 +332,7 @@ fn middle_column_bottom() {
     .iter()
     {
         let position = Position::new(column.to_owned(), bottom.clone());
-        assert!(position.is_middle_column_bottom());
+        assert!(position.is_middle_column_bottom(&RuleSet::default()));
     }
 }
 
@@ -314,39 +341,33 @@ fn active_righthand() {
     let x = Column::LeftEdge;
     let y = Row::Top;
     let position = Position::new(x, y.clone());
-    let moved_to_middle_first_column = position.righthand();
+    let moved_to_middle_first_column = position.righthand(&RuleSet::default());
     assert_eq!(
         &moved_to_middle_first_column,
-        &Ok(Position {
-            x: Column::MiddleFirst,
-            y: y.clone(),
-        })
+        &Ok(Position::new(Column::MiddleFirst, y.clone()))
     );
-    let moved_to_middle_second_column = moved_to_middle_first_column.unwrap().righthand();
+    let moved_to_middle_second_column = moved_to_middle_first_column
+        .unwrap()
+        .righthand(&RuleSet::default());
     assert_eq!(
         &moved_to_middle_second_column,
-        &Ok(Position {
-            x: Column::MiddleSecond,
-            y: y.clone(),
-        })
+        &Ok(Position::new(Column::MiddleSecond, y.clone()))
     );
-    let moved_to_middle_third_column = moved_to_middle_second_column.unwrap().righthand();
+    let moved_to_middle_third_column = moved_to_middle_second_column
+        .unwrap()
+        .righthand(&RuleSet::default());
     assert_eq!(
         &moved_to_middle_third_column,
-        &Ok(Position {
-            x: Column::MiddleThird,
-            y: y.clone(),
-        })
+        &Ok(Position::new(Column::MiddleThird, y.clone()))
     );
-    let moved_to_right_edge = moved_to_middle_third_column.unwrap().righthand();
+    let moved_to_right_edge = moved_to_middle_third_column
+        .unwrap()
+        .righthand(&RuleSet::default());
     assert_eq!(
         &moved_to_right_edge,
-        &Ok(Position {
-            x: Column::RightEdge,
-            y: y.clone(),
-        })
+        &Ok(Position::new(Column::RightEdge, y.clone()))
     );
-    let cannot_move_to_right = moved_to_right_edge.unwrap().righthand();
+    let cannot_move_to_right = moved_to_right_edge.unwrap().righthand(&RuleSet::default());
     assert_eq!(&cannot_move_to_right, &Err(Error::ReachedRightEdge));
 }
 
@@ -358,34 +379,22 @@ fn active_lefthand() {
     let moved_to_middle_third_column = position.lefthand();
     assert_eq!(
         &moved_to_middle_third_column,
-        &Ok(Position {
-            x: Column::MiddleThird,
-            y: y.clone(),
-        })
+        &Ok(Position::new(Column::MiddleThird, y.clone()))
     );
     let moved_to_middle_second_column = moved_to_middle_third_column.unwrap().lefthand();
     assert_eq!(
         &moved_to_middle_second_column,
-        &Ok(Position {
-            x: Column::MiddleSecond,
-            y: y.clone(),
-        })
+        &Ok(Position::new(Column::MiddleSecond, y.clone()))
     );
     let moved_to_middle_first_column = moved_to_middle_second_column.unwrap().lefthand();
     assert_eq!(
         &moved_to_middle_first_column,
-        &Ok(Position {
-            x: Column::MiddleFirst,
-            y: y.clone(),
-        })
+        &Ok(Position::new(Column::MiddleFirst, y.clone()))
     );
     let moved_to_left_edge = moved_to_middle_first_column.unwrap().lefthand();
     assert_eq!(
         &moved_to_left_edge,
-        &Ok(Position {
-            x: Column::LeftEdge,
-            y: y.clone(),
-        })
+        &Ok(Position::new(Column::LeftEdge, y.clone()))
     );
     let cannot_move_to_left = moved_to_left_edge.unwrap().lefthand();
     assert_eq!(&cannot_move_to_left, &Err(Error::ReachedLeftEdge));
@@ -399,43 +408,25 @@ fn active_above() {
     let moved_to_middle_fourth_row = position.above();
     assert_eq!(
         &moved_to_middle_fourth_row,
-        &Ok(Position {
-            x: x.clone(),
-            y: Row::MiddleFourth,
-        })
+        &Ok(Position::new(x.clone(), Row::MiddleFourth))
     );
     let moved_to_middle_third_row = moved_to_middle_fourth_row.unwrap().above();
     assert_eq!(
         &moved_to_middle_third_row,
-        &Ok(Position {
-            x: x.clone(),
-            y: Row::MiddleThird,
-        })
+        &Ok(Position::new(x.clone(), Row::MiddleThird))
     );
     let moved_to_middle_second_row = moved_to_middle_third_row.unwrap().above();
     assert_eq!(
         &moved_to_middle_second_row,
-        &Ok(Position {
-            x: x.clone(),
-            y: Row::MiddleSecond,
-        })
+        &Ok(Position::new(x.clone(), Row::MiddleSecond))
     );
     let moved_to_middle_first_row = moved_to_middle_second_row.unwrap().above();
     assert_eq!(
         &moved_to_middle_first_row,
-        &Ok(Position {
-            x: x.clone(),
-            y: Row::MiddleFirst,
-        })
+        &Ok(Position::new(x.clone(), Row::MiddleFirst))
     );
     let moved_to_top = moved_to_middle_first_row.unwrap().above();
-    assert_eq!(
-        &moved_to_top,
-        &Ok(Position {
-            x: x.clone(),
-            y: Row::Top,
-        })
-    );
+    assert_eq!(&moved_to_top, &Ok(Position::new(x.clone(), Row::Top)));
     let cannot_move_to_top = moved_to_top.unwrap().above();
     assert_eq!(&cannot_move_to_top, &Err(Error::ReachedTop));
 }
@@ -445,65 +436,55 @@ fn active_below() {
     let x = Column::LeftEdge;
     let y = Row::Top;
     let position = Position::new(x.clone(), y);
-    let moved_to_middle_first_row = position.below();
+    let moved_to_middle_first_row = position.below(&RuleSet::default());
     assert_eq!(
         &moved_to_middle_first_row,
-        &Ok(Position {
-            x: x.clone(),
-            y: Row::MiddleFirst,
-        })
+        &Ok(Position::new(x.clone(), Row::MiddleFirst))
     );
-    let moved_to_middle_second_row = moved_to_middle_first_row.unwrap().below();
+    let moved_to_middle_second_row = moved_to_middle_first_row
+        .unwrap()
+        .below(&RuleSet::default());
     assert_eq!(
         &moved_to_middle_second_row,
-        &Ok(Position {
-            x: x.clone(),
-            y: Row::MiddleSecond,
-        })
+        &Ok(Position::new(x.clone(), Row::MiddleSecond))
     );
-    let moved_to_middle_third_row = moved_to_middle_second_row.unwrap().below();
+    let moved_to_middle_third_row = moved_to_middle_second_row
+        .unwrap()
+        .below(&RuleSet::default());
     assert_eq!(
         &moved_to_middle_third_row,
-        &Ok(Position {
-            x: x.clone(),
-            y: Row::MiddleThird,
-        })
+        &Ok(Position::new(x.clone(), Row::MiddleThird))
     );
-    let moved_to_middle_fourth_row = moved_to_middle_third_row.unwrap().below();
+    let moved_to_middle_fourth_row = moved_to_middle_third_row
+        .unwrap()
+        .below(&RuleSet::default());
     assert_eq!(
         &moved_to_middle_fourth_row,
-        &Ok(Position {
-            x: x.clone(),
-            y: Row::MiddleFourth,
-        })
+        &Ok(Position::new(x.clone(), Row::MiddleFourth))
     );
-    let moved_to_bottom = moved_to_middle_fourth_row.unwrap().below();
-    assert_eq!(
-        &moved_to_bottom,
-        &Ok(Position {
-            x: x.clone(),
-            y: Row::Bottom,
-        })
-    );
-    let cannot_move_to_bottom = moved_to_bottom.unwrap().below();
+    let moved_to_bottom = moved_to_middle_fourth_row
+        .unwrap()
+        .below(&RuleSet::default());
+    assert_eq!(&moved_to_bottom, &Ok(Position::new(x.clone(), Row::Bottom)));
+    let cannot_move_to_bottom = moved_to_bottom.unwrap().below(&RuleSet::default());
     assert_eq!(&cannot_move_to_bottom, &Err(Error::ReachedBottom));
 }
 
 #[test]
 fn active_above_righthand() {
     let position = Position::new(Column::MiddleThird, Row::MiddleFirst);
-    let moved_to_right_top_corner = position.above_righthand();
+    let moved_to_right_top_corner = position.above_righthand(&RuleSet::default());
     assert_eq!(
         &moved_to_right_top_corner,
-        &Ok(Position {
-            x: Column::RightEdge,
-            y: Row::Top,
-        }),
+        &Ok(Position::new(Column::RightEdge, Row::Top)),
     );
     let right_top_corner = moved_to_right_top_corner.unwrap();
-    assert_eq!(right_top_corner.above_righthand(), Err(Error::ReachedTop),);
     assert_eq!(
-        Position::new(Column::RightEdge, Row::MiddleFirst).above_righthand(),
+        right_top_corner.above_righthand(&RuleSet::default()),
+        Err(Error::ReachedTop),
+    );
+    assert_eq!(
+        Position::new(Column::RightEdge, Row::MiddleFirst).above_righthand(&RuleSet::default()),
         Err(Error::ReachedRightEdge),
     );
 }
@@ -514,10 +495,7 @@ fn active_above_lefthand() {
     let moved_to_left_top_corner = position.above_lefthand();
     assert_eq!(
         &moved_to_left_top_corner,
-        &Ok(Position {
-            x: Column::LeftEdge,
-            y: Row::Top,
-        }),
+        &Ok(Position::new(Column::LeftEdge, Row::Top)),
     );
     let left_top_corner = moved_to_left_top_corner.unwrap();
     assert_eq!(left_top_corner.above_lefthand(), Err(Error::ReachedTop),);
@@ -530,21 +508,18 @@ fn active_above_lefthand() {
 #[test]
 fn active_below_righthand() {
     let position = Position::new(Column::MiddleThird, Row::MiddleFourth);
-    let moved_to_right_bottom_corner = position.below_righthand();
+    let moved_to_right_bottom_corner = position.below_righthand(&RuleSet::default());
     assert_eq!(
         &moved_to_right_bottom_corner,
-        &Ok(Position {
-            x: Column::RightEdge,
-            y: Row::Bottom,
-        }),
+        &Ok(Position::new(Column::RightEdge, Row::Bottom)),
     );
     let right_bottom_corner = moved_to_right_bottom_corner.unwrap();
     assert_eq!(
-        right_bottom_corner.below_righthand(),
+        right_bottom_corner.below_righthand(&RuleSet::default()),
         Err(Error::ReachedBottom),
     );
     assert_eq!(
-        Position::new(Column::RightEdge, Row::MiddleFourth).below_righthand(),
+        Position::new(Column::RightEdge, Row::MiddleFourth).below_righthand(&RuleSet::default()),
         Err(Error::ReachedRightEdge),
     );
 }
@@ -552,25 +527,40 @@ fn active_below_righthand() {
 #[test]
 fn active_below_lefthand() {
     let position = Position::new(Column::MiddleFirst, Row::MiddleFourth);
-    let moved_to_left_bottom_corner = position.below_lefthand();
+    let moved_to_left_bottom_corner = position.below_lefthand(&RuleSet::default());
     assert_eq!(
         &moved_to_left_bottom_corner,
-        &Ok(Position {
-            x: Column::LeftEdge,
-            y: Row::Bottom,
-        }),
+        &Ok(Position::new(Column::LeftEdge, Row::Bottom)),
     );
     let left_bottom_corner = moved_to_left_bottom_corner.unwrap();
     assert_eq!(
-        left_bottom_corner.below_lefthand(),
+        left_bottom_corner.below_lefthand(&RuleSet::default()),
         Err(Error::ReachedBottom),
     );
     assert_eq!(
-        Position::new(Column::LeftEdge, Row::MiddleFourth).below_lefthand(),
+        Position::new(Column::LeftEdge, Row::MiddleFourth).below_lefthand(&RuleSet::default()),
         Err(Error::ReachedLeftEdge),
     );
 }
 
+#[test]
+fn bounded_by_rule_set() {
+    let rule_set = RuleSet {
+        columns: 7,
+        rows: 8,
+        ..RuleSet::default()
+    };
+    let position = Position::new(Column::RightEdge, Row::Bottom);
+    assert_eq!(position.righthand(&rule_set), Ok(Position::at(5, 5)));
+    assert_eq!(position.below(&rule_set), Ok(Position::at(4, 6)));
+    let corner = Position::at(6, 7);
+    assert!(corner.is_inside(&rule_set));
+    assert!(corner.is_right_edge_bottom(&rule_set));
+    assert!(!corner.is_inside(&RuleSet::default()));
+    assert_eq!(corner.righthand(&rule_set), Err(Error::ReachedRightEdge));
+    assert_eq!(corner.below(&rule_set), Err(Error::ReachedBottom));
+}
+
 #[test]
 fn row_order() {
     assert!(Row::Top < Row::MiddleFirst);
@@ -630,6 +620,40 @@ fn row_iterator() {
         Row::Bottom,
     ];
     for (i, value) in iter.enumerate() {
-        assert_eq!(value,expected[i]);
+        assert_eq!(value, expected[i]);
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn notation() {
+    let position = Position::new(Column::LeftEdge, Row::Top);
+    assert_eq!(position.to_string(), "a1");
+    assert_eq!(
+        Position::new(Column::RightEdge, Row::Bottom).to_string(),
+        "e6"
+    );
+    assert_eq!(Position::at(6, 11).to_string(), "g12");
+    assert_eq!(Position::at(25, 0).to_string(), "z1");
+    assert_eq!(Position::at(26, 0).to_string(), "[26]1");
+    assert_eq!(Position::at(200, 0).to_string(), "[200]1");
+    assert_eq!("a1".parse::<Position>(), Ok(position));
+    assert_eq!("g12".parse::<Position>(), Ok(Position::at(6, 11)));
+    for invalid in ["", "a", "1", "a0", "A1", "a+1", "a1b", "1a"].iter() {
+        assert_eq!(invalid.parse::<Position>(), Err(Error::InvalidNotation));
+    }
+}
+
+#[test]
+fn notation_round_trip() {
+    let rule_set = RuleSet {
+        columns: 7,
+        rows: 8,
+        ..RuleSet::default()
+    };
+    for x in 0..rule_set.columns {
+        for y in 0..rule_set.rows {
+            let position = Position::at(x, y);
+            assert_eq!(position.to_string().parse::<Position>(), Ok(position));
+        }
+    }
+}
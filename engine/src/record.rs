@@ -0,0 +1,242 @@
+use std::convert::TryFrom;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    board::Board,
+    outcome::{Outcome, WinReason},
+    player::Player,
+    Action, Game,
+};
+
+// A game is stored as the position it started from and the moves played since,
+// so loading replays and re-validates every move and rebuilds the repetition
+// history without depending on hash keys.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct GameRecord {
+    start: Board,
+    side: Player,
+    moves: Vec<Action>,
+    outcome: Outcome,
+}
+
+impl From<Game> for GameRecord {
+    fn from(game: Game) -> Self {
+        let (start, side) = game.history.start();
+        Self {
+            start: start.clone(),
+            side,
+            moves: game.history.actions(),
+            outcome: game.outcome,
+        }
+    }
+}
+
+impl TryFrom<GameRecord> for Game {
+    type Error = &'static str;
+
+    fn try_from(record: GameRecord) -> Result<Self, Self::Error> {
+        if record.side.id >= 2 {
+            return Err("player id is out of range");
+        }
+        let mut game = Game::from_board(record.start, record.side);
+        for action in record.moves.iter() {
+            game.apply(action).map_err(|_| "illegal move")?;
+        }
+        match (game.outcome, record.outcome) {
+            (played, recorded) if played == recorded => {}
+            (Outcome::InProgress, Outcome::Win(player, WinReason::Resignation))
+            | (Outcome::InProgress, Outcome::Win(player, WinReason::Timeout))
+                if player.id < 2 =>
+            {
+                game.outcome = record.outcome;
+            }
+            _ => return Err("outcome does not match the moves"),
+        }
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod serde_spec {
+    use crate::{
+        board::{Board, Direction},
+        cell::Cell,
+        fixture::OPENING,
+        player::Player,
+        position::Position,
+        rule::RuleSet,
+        session::GameSession,
+        Action, Game,
+    };
+    use serde::{de::DeserializeOwned, Serialize};
+    use std::fmt::Debug;
+
+    fn round_trip<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: &T) {
+        let json = serde_json::to_string(value).unwrap();
+        assert_eq!(
+            &serde_json::from_str::<T>(&json).unwrap(),
+            value,
+            "{}",
+            json
+        );
+    }
+
+    fn played() -> Game {
+        Game::default().play(&OPENING)
+    }
+
+    #[test]
+    fn primitives_round_trip() {
+        round_trip(&Player::new(1));
+        round_trip(&Position::at(3, 4));
+        round_trip(&Direction::UpLeft);
+        round_trip(&"b6>a5".parse::<Action>().unwrap());
+        round_trip(&RuleSet::default());
+        round_trip(
+            &Cell::new_occupied(Player::new(0))
+                .stack(&Player::new(1), 3)
+                .unwrap(),
+        );
+    }
+
+    #[test]
+    fn game_round_trip() {
+        let game = played();
+        round_trip(&game.board);
+        round_trip(&game);
+        let restored: Game = serde_json::from_str(&serde_json::to_string(&game).unwrap()).unwrap();
+        let action = "c1-S".parse::<Action>().unwrap();
+        assert_eq!(restored.accept(&action), game.accept(&action));
+        assert_eq!(restored.legal_actions(), game.legal_actions());
+    }
+
+    #[test]
+    fn session_round_trip() {
+        let mut session = GameSession::new(Game::default());
+        session.accept(&"a1-S".parse::<Action>().unwrap()).unwrap();
+        session.accept(&"a6-N".parse::<Action>().unwrap()).unwrap();
+        session.undo().unwrap();
+        round_trip(&session);
+    }
+
+    #[test]
+    fn json_shape() {
+        assert_eq!(
+            serde_json::to_value(Player::new(1)).unwrap(),
+            serde_json::json!(1)
+        );
+        assert_eq!(
+            serde_json::to_value(Position::at(2, 3)).unwrap(),
+            serde_json::json!({"x": 2, "y": 3}),
+        );
+        assert_eq!(
+            serde_json::to_value("a1-S".parse::<Action>().unwrap()).unwrap(),
+            serde_json::json!({"from": {"x": 0, "y": 0}, "direction": "Down"}),
+        );
+        let board = serde_json::to_value(Board::new(
+            RuleSet::default(),
+            &Player::new(0),
+            &Player::new(1),
+        ))
+        .unwrap();
+        let cells = board["cell_map"].as_array().unwrap();
+        assert_eq!(cells.len(), 30);
+        let (position, cell) = (&cells[0][0], &cells[0][1]);
+        let position: Position = serde_json::from_value(position.clone()).unwrap();
+        let cell: Cell = serde_json::from_value(cell.clone()).unwrap();
+        assert_eq!(
+            Board::new(RuleSet::default(), &Player::new(0), &Player::new(1)).cell(&position),
+            Ok(cell),
+        );
+        assert_eq!(board["rule_set"]["stalemate"], serde_json::json!("Pass"));
+        assert_eq!(
+            serde_json::to_value(
+                Cell::new_occupied(Player::new(1))
+                    .stack(&Player::new(0), 3)
+                    .unwrap()
+            )
+            .unwrap(),
+            serde_json::json!([1, 0]),
+        );
+        let game = serde_json::to_value(played()).unwrap();
+        assert_eq!(game["side"], serde_json::json!(0));
+        assert_eq!(game["moves"].as_array().unwrap().len(), 6);
+        assert_eq!(game["outcome"], serde_json::json!("InProgress"));
+    }
+
+    fn with_cell(
+        game: &serde_json::Value,
+        x: usize,
+        y: usize,
+        cell: serde_json::Value,
+    ) -> serde_json::Value {
+        let mut game = game.clone();
+        let entry = game["start"]["cell_map"]
+            .as_array_mut()
+            .unwrap()
+            .iter_mut()
+            .find(|entry| entry[0] == serde_json::json!({"x": x, "y": y}))
+            .unwrap();
+        entry[1] = cell;
+        game
+    }
+
+    #[test]
+    fn rejects_invalid_data() {
+        let game = serde_json::to_value(played()).unwrap();
+        assert!(serde_json::from_value::<Game>(game.clone()).is_ok());
+        let mut cases = vec![
+            with_cell(&game, 0, 0, serde_json::json!([0, null, 1])),
+            with_cell(&game, 0, 0, serde_json::json!([1, 0, 0])),
+            with_cell(&game, 0, 0, serde_json::json!([7])),
+            with_cell(&game, 0, 0, serde_json::json!([0, 1, 0, 1])),
+        ];
+        let mut outside = game.clone();
+        outside["start"]["cell_map"]
+            .as_array_mut()
+            .unwrap()
+            .push(serde_json::json!([{"x": 9, "y": 0}, []]));
+        cases.push(outside);
+        let mut twice = game.clone();
+        let first = twice["start"]["cell_map"][0].clone();
+        twice["start"]["cell_map"]
+            .as_array_mut()
+            .unwrap()
+            .push(first);
+        cases.push(twice);
+        let mut side = game.clone();
+        side["side"] = serde_json::json!(7);
+        cases.push(side);
+        let mut moves = game.clone();
+        moves["moves"][0] = serde_json::to_value("a6-N".parse::<Action>().unwrap()).unwrap();
+        cases.push(moves);
+        let mut outcome = game.clone();
+        outcome["outcome"] = serde_json::json!({"Win": [0, "Isolation"]});
+        cases.push(outcome);
+        let mut resigned = game.clone();
+        resigned["outcome"] = serde_json::json!({"Win": [7, "Resignation"]});
+        cases.push(resigned);
+        for case in cases.into_iter() {
+            assert!(
+                serde_json::from_value::<Game>(case.clone()).is_err(),
+                "{}",
+                case
+            );
+        }
+        let mut resigned = game;
+        resigned["outcome"] = serde_json::json!({"Win": [1, "Resignation"]});
+        assert!(serde_json::from_value::<Game>(resigned).unwrap().is_over());
+    }
+
+    #[test]
+    fn rejects_invalid_session() {
+        let mut session = serde_json::to_value(GameSession::new(Game::default())).unwrap();
+        assert!(serde_json::from_value::<GameSession>(session.clone()).is_ok());
+        session["cursor"] = serde_json::json!(1);
+        assert!(serde_json::from_value::<GameSession>(session.clone()).is_err());
+        session["history"] = serde_json::json!([]);
+        session["cursor"] = serde_json::json!(0);
+        assert!(serde_json::from_value::<GameSession>(session).is_err());
+    }
+}
@@ -1,4 +1,8 @@
-use crate::player::Player;
+use std::fmt;
+
+use crate::{
+    board::Direction, fen::player_symbol, outcome::Outcome, player::Player, position::Position,
+};
 
 #[derive(Debug, Eq, PartialEq)]
 pub enum Error {
@@ -9,12 +13,119 @@ pub enum Error {
     ReachedPalletHeightLimit,
     CellIsEmpty,
     CellIsFullfilled,
-    AlreadyOccupied(Player),
-    IllegalDestination,
+    AlreadyOccupied(Position, Player),
+    StackedOnOwnPiece(Player),
+    OwnedByOpponent(Player),
+    IllegalDestination(Position, Direction),
+    NotAdjacent(Position, Position),
     InvalidPosition,
     CellNotFound,
     SamePositionCannotBeMigrated,
-    GameIsOver,
+    GameIsOver(Outcome),
+    InvalidPlayer,
+    InvalidRuleSet,
+    NothingToUndo,
+    NothingToRedo,
+    PlyOutOfRange,
+    InvalidNotation,
+    InvalidBoardSize,
+    PalletHasGap,
+    InvalidPieceCount(Player),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ReachedTop => f.write_str("position is already on the top row"),
+            Self::ReachedBottom => f.write_str("position is already on the bottom row"),
+            Self::ReachedRightEdge => f.write_str("position is already on the right edge"),
+            Self::ReachedLeftEdge => f.write_str("position is already on the left edge"),
+            Self::ReachedPalletHeightLimit => f.write_str("pallet height limit is reached"),
+            Self::CellIsEmpty => f.write_str("cell is empty"),
+            Self::CellIsFullfilled => f.write_str("cell is fullfilled"),
+            Self::AlreadyOccupied(position, player) => write!(
+                f,
+                "{} is already occupied by player {}",
+                position,
+                player_symbol(player)
+            ),
+            Self::StackedOnOwnPiece(player) => write!(
+                f,
+                "player {} cannot be stacked on its own piece",
+                player_symbol(player)
+            ),
+            Self::OwnedByOpponent(player) => {
+                write!(f, "cell is owned by player {}", player_symbol(player))
+            }
+            Self::IllegalDestination(position, direction) => {
+                write!(f, "{} cannot move to {}", position, direction)
+            }
+            Self::NotAdjacent(from, to) => write!(f, "{} is not adjacent to {}", to, from),
+            Self::InvalidPosition => f.write_str("position is out of the field"),
+            Self::CellNotFound => f.write_str("cell is not found"),
+            Self::SamePositionCannotBeMigrated => {
+                f.write_str("cell cannot be migrated to the same position")
+            }
+            Self::GameIsOver(outcome) => write!(f, "game is already over: {}", outcome),
+            Self::InvalidPlayer => f.write_str("player is unknown"),
+            Self::InvalidRuleSet => f.write_str("rule set is invalid"),
+            Self::NothingToUndo => f.write_str("nothing to undo"),
+            Self::NothingToRedo => f.write_str("nothing to redo"),
+            Self::PlyOutOfRange => f.write_str("ply is out of range"),
+            Self::InvalidNotation => f.write_str("notation is invalid"),
+            Self::InvalidBoardSize => f.write_str("board size does not match the rule set"),
+            Self::PalletHasGap => f.write_str("pallet has a gap"),
+            Self::InvalidPieceCount(player) => write!(
+                f,
+                "player {} has an invalid number of pieces",
+                player_symbol(player)
+            ),
+        }
+    }
 }
 
+impl std::error::Error for Error {}
+
 pub type Result<T> = std::result::Result<T, Error>;
+
+#[cfg(test)]
+mod error_spec {
+    use super::Error;
+    use crate::{
+        board::Direction,
+        outcome::{Outcome, WinReason},
+        player::Player,
+        position::Position,
+    };
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            Error::IllegalDestination(Position::at(0, 0), Direction::Up).to_string(),
+            "a1 cannot move to N"
+        );
+        assert_eq!(
+            Error::AlreadyOccupied(Position::at(1, 2), Player::new(1)).to_string(),
+            "b3 is already occupied by player b"
+        );
+        assert_eq!(
+            Error::NotAdjacent(Position::at(0, 0), Position::at(2, 0)).to_string(),
+            "c1 is not adjacent to a1"
+        );
+        assert_eq!(
+            Error::GameIsOver(Outcome::Win(Player::new(0), WinReason::Isolation)).to_string(),
+            "game is already over: player a won by isolation"
+        );
+    }
+
+    #[test]
+    fn into_boxed_error() {
+        fn parse(s: &str) -> Result<crate::Action, Box<dyn std::error::Error>> {
+            Ok(s.parse()?)
+        }
+        assert_eq!(
+            parse("a1-X").unwrap_err().to_string(),
+            "notation is invalid"
+        );
+    }
+}
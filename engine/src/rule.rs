@@ -0,0 +1,115 @@
+pub use crate::cell::MAX_PALLET_HEIGHT;
+use crate::result::{Error, Result};
+
+pub const MAX_COLUMNS: usize = 26;
+pub const MAX_ROWS: usize = 26;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Stalemate {
+    Pass,
+    Loss,
+    Draw,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RuleSet {
+    pub columns: usize,
+    pub rows: usize,
+    pub pallet_height: usize,
+    pub stalemate: Stalemate,
+    pub repetition: Option<usize>,
+    pub ply_limit: Option<usize>,
+}
+
+impl RuleSet {
+    pub(crate) fn validate(&self) -> Result<()> {
+        if self.columns == 0
+            || self.columns > MAX_COLUMNS
+            || self.rows < 2
+            || self.rows > MAX_ROWS
+            || self.pallet_height < 2
+            || self.pallet_height > MAX_PALLET_HEIGHT
+            || matches!(self.repetition, Some(repetition) if repetition < 2)
+            || self.ply_limit == Some(0)
+        {
+            Err(Error::InvalidRuleSet)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self {
+            columns: 5,
+            rows: 6,
+            pallet_height: 3,
+            stalemate: Stalemate::Pass,
+            repetition: Some(3),
+            ply_limit: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod rule_set_spec {
+    use super::{RuleSet, MAX_COLUMNS, MAX_ROWS};
+    use crate::{cell::MAX_PALLET_HEIGHT, result::Error};
+
+    #[test]
+    fn default_is_valid() {
+        assert_eq!(RuleSet::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn invalid_size() {
+        for (columns, rows) in [
+            (0, 6),
+            (MAX_COLUMNS + 1, 6),
+            (5, 1),
+            (5, 0),
+            (5, MAX_ROWS + 1),
+        ]
+        .iter()
+        {
+            let rule_set = RuleSet {
+                columns: *columns,
+                rows: *rows,
+                ..RuleSet::default()
+            };
+            assert_eq!(rule_set.validate(), Err(Error::InvalidRuleSet));
+        }
+    }
+
+    #[test]
+    fn invalid_pallet_height() {
+        for pallet_height in [0, 1, MAX_PALLET_HEIGHT + 1].iter() {
+            let rule_set = RuleSet {
+                pallet_height: *pallet_height,
+                ..RuleSet::default()
+            };
+            assert_eq!(rule_set.validate(), Err(Error::InvalidRuleSet));
+        }
+    }
+
+    #[test]
+    fn invalid_draw_rules() {
+        for (repetition, ply_limit) in [(Some(0), None), (Some(1), None), (None, Some(0))].iter() {
+            let rule_set = RuleSet {
+                repetition: *repetition,
+                ply_limit: *ply_limit,
+                ..RuleSet::default()
+            };
+            assert_eq!(rule_set.validate(), Err(Error::InvalidRuleSet));
+        }
+        let rule_set = RuleSet {
+            repetition: Some(2),
+            ply_limit: Some(1),
+            ..RuleSet::default()
+        };
+        assert_eq!(rule_set.validate(), Ok(()));
+    }
+}
@@ -0,0 +1,198 @@
+use crate::{
+    result::{Error, Result},
+    Action, Game,
+};
+
+/// With the `serde` feature a session is written as
+/// `{"history": [Game, ...], "cursor": 0}`; reading rejects an empty history
+/// and a cursor past its end.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "SessionRecord"))]
+pub struct GameSession {
+    history: Vec<Game>,
+    cursor: usize,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct SessionRecord {
+    history: Vec<Game>,
+    cursor: usize,
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<SessionRecord> for GameSession {
+    type Error = &'static str;
+
+    fn try_from(record: SessionRecord) -> std::result::Result<Self, Self::Error> {
+        if record.cursor >= record.history.len() {
+            return Err("cursor is out of the history");
+        }
+        Ok(Self {
+            history: record.history,
+            cursor: record.cursor,
+        })
+    }
+}
+
+impl GameSession {
+    pub fn new(game: Game) -> Self {
+        Self {
+            history: vec![game],
+            cursor: 0,
+        }
+    }
+
+    pub fn current(&self) -> &Game {
+        &self.history[self.cursor]
+    }
+
+    pub fn ply(&self) -> usize {
+        self.current().ply()
+    }
+
+    pub fn accept(&mut self, action: &Action) -> Result<&Game> {
+        let game = self.current().accept(action)?;
+        self.history.truncate(self.cursor + 1);
+        self.history.push(game);
+        self.cursor += 1;
+        Ok(self.current())
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.cursor > 0
+    }
+
+    pub fn can_redo(&self) -> bool {
+        self.cursor + 1 < self.history.len()
+    }
+
+    pub fn undo(&mut self) -> Result<&Game> {
+        if !self.can_undo() {
+            return Err(Error::NothingToUndo);
+        }
+        self.cursor -= 1;
+        Ok(self.current())
+    }
+
+    pub fn redo(&mut self) -> Result<&Game> {
+        if !self.can_redo() {
+            return Err(Error::NothingToRedo);
+        }
+        self.cursor += 1;
+        Ok(self.current())
+    }
+
+    pub fn jump_to(&mut self, ply: usize) -> Result<&Game> {
+        let first_ply = self.history[0].ply();
+        let index = ply
+            .checked_sub(first_ply)
+            .filter(|index| index < &self.history.len())
+            .ok_or(Error::PlyOutOfRange)?;
+        self.cursor = index;
+        Ok(self.current())
+    }
+}
+
+#[cfg(test)]
+mod game_session_spec {
+    use super::GameSession;
+    use crate::{
+        board::Direction,
+        position::{Column, Position, Row},
+        result::Error,
+        Action, Game,
+    };
+
+    fn actions() -> [Action; 3] {
+        [
+            Action::new(Position::new(Column::LeftEdge, Row::Top), Direction::Down),
+            Action::new(Position::new(Column::LeftEdge, Row::Bottom), Direction::Up),
+            Action::new(
+                Position::new(Column::MiddleFirst, Row::Top),
+                Direction::Down,
+            ),
+        ]
+    }
+
+    fn played_session() -> GameSession {
+        let mut session = GameSession::new(Game::default());
+        actions().iter().for_each(|action| {
+            session.accept(action).unwrap();
+        });
+        session
+    }
+
+    #[test]
+    fn new() {
+        let session = GameSession::new(Game::default());
+        assert_eq!(session.current(), &Game::default());
+        assert_eq!(session.ply(), 0);
+        assert!(!session.can_undo());
+        assert!(!session.can_redo());
+    }
+
+    #[test]
+    fn accept() {
+        let session = played_session();
+        let expected = actions()
+            .iter()
+            .fold(Game::default(), |game, action| game.accept(action).unwrap());
+        assert_eq!(session.current(), &expected);
+        assert_eq!(session.ply(), 3);
+    }
+
+    #[test]
+    fn illegal_action_keeps_history() {
+        let mut session = played_session();
+        let before = session.clone();
+        let opponents_cell = Position::new(Column::RightEdge, Row::Top);
+        assert_eq!(
+            session.accept(&Action::new(opponents_cell, Direction::Down)),
+            Err(Error::OwnedByOpponent(Game::default().current_player()))
+        );
+        assert_eq!(session, before);
+    }
+
+    #[test]
+    fn undo_and_redo() {
+        let mut session = played_session();
+        let last = session.current().clone();
+        let previous = session.undo().unwrap().clone();
+        assert_eq!(previous.ply(), 2);
+        assert_eq!(previous.accept(&actions()[2]), Ok(last.clone()));
+        assert!(session.can_redo());
+        assert_eq!(session.redo(), Ok(&last));
+        assert_eq!(session.redo(), Err(Error::NothingToRedo));
+        session.undo().unwrap();
+        session.undo().unwrap();
+        session.undo().unwrap();
+        assert_eq!(session.current(), &Game::default());
+        assert_eq!(session.undo(), Err(Error::NothingToUndo));
+    }
+
+    #[test]
+    fn accept_after_undo_discards_redo() {
+        let mut session = played_session();
+        session.undo().unwrap();
+        session
+            .accept(&Action::new(
+                Position::new(Column::RightEdge, Row::Top),
+                Direction::Down,
+            ))
+            .unwrap();
+        assert_eq!(session.ply(), 3);
+        assert!(!session.can_redo());
+    }
+
+    #[test]
+    fn jump_to() {
+        let mut session = played_session();
+        assert_eq!(session.jump_to(0), Ok(&Game::default()));
+        assert!(session.can_redo());
+        assert_eq!(session.jump_to(2).map(|game| game.ply()), Ok(2));
+        assert_eq!(session.jump_to(4), Err(Error::PlyOutOfRange));
+        assert_eq!(session.ply(), 2);
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{cell::Cell, cell::MAX_PALLET_HEIGHT, player::Player};
+
+const SEED: u64 = 0x6e63_325f_7a6f_6272;
+
+pub(crate) fn splitmix64(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+fn piece(index: usize, level: usize, player: &Player) -> u64 {
+    let square = (index * MAX_PALLET_HEIGHT + level) as u64;
+    splitmix64(SEED ^ (square << 8 | player.id as u64))
+}
+
+pub(crate) fn cell(index: usize, cell: &Cell) -> u64 {
+    cell.pallet()
+        .iter()
+        .enumerate()
+        .filter_map(|(level, player)| player.map(|player| piece(index, level, &player)))
+        .fold(0, |hash, key| hash ^ key)
+}
+
+pub(crate) fn side(player: &Player) -> u64 {
+    splitmix64(!SEED ^ player.id as u64)
+}
+
+#[cfg(test)]
+mod zobrist_spec {
+    use super::{cell, piece, side};
+    use crate::{cell::Cell, player::Player};
+    use std::collections::HashSet;
+
+    #[test]
+    fn keys_are_distinct() {
+        let players = [Player::new(0), Player::new(1)];
+        let mut keys = HashSet::new();
+        for index in 0..30 {
+            for level in 0..3 {
+                for player in players.iter() {
+                    assert!(keys.insert(piece(index, level, player)));
+                }
+            }
+        }
+        for player in players.iter() {
+            assert!(keys.insert(side(player)));
+        }
+    }
+
+    #[test]
+    fn cell_combines_levels() {
+        let (player_a, player_b) = (Player::new(0), Player::new(1));
+        assert_eq!(cell(3, &Cell::new_empty()), 0);
+        let stacked = Cell::new_occupied(player_a).stack(&player_b, 3).unwrap();
+        assert_eq!(
+            cell(3, &stacked),
+            piece(3, 0, &player_a) ^ piece(3, 1, &player_b)
+        );
+        assert_ne!(cell(3, &stacked), cell(4, &stacked));
+    }
+}
@@ -1,6 +1,7 @@
 use engine::{
     board::Direction,
     position::{Column, Position, Row},
+    session::GameSession,
     Action, Game,
 };
 use wasm_bindgen::prelude::*;
@@ -10,28 +11,34 @@ static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
 
 #[wasm_bindgen]
 struct Battle {
-    game: Game,
-    history: Vec<Game>,
+    session: GameSession,
 }
 
 #[wasm_bindgen]
 impl Battle {
     pub fn new() -> Self {
-        let game = Game::new();
-        let history = vec![game.clone()];
-        Self { game, history }
+        let session = GameSession::new(Game::default());
+        Self { session }
+    }
+
+    pub fn undo(&mut self) -> bool {
+        self.session.undo().is_ok()
+    }
+
+    pub fn redo(&mut self) -> bool {
+        self.session.redo().is_ok()
     }
 
     pub fn display_board(&self) -> String {
-        let board = self.game.board.clone();
+        let board = &self.session.current().board;
         board
             .iterate()
             .map(|pos| {
-                let cell = board.cell_map.get(&pos).unwrap();
-                if let Some(player) = cell.owner() {
-                    player.id.to_string()
-                } else {
+                let cell = board.cell(&pos).unwrap();
+                if cell.is_empty() {
                     " ".to_string()
+                } else {
+                    cell.players().map(|player| player.id.to_string()).collect()
                 }
             })
             .collect::<Vec<String>>()